@@ -0,0 +1,81 @@
+//! Reading puzzle input, either from a file, stdin, or the input bundled with a
+//! day's crate.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Declares where a day's bundled puzzle input lives, i.e. `src/input.txt`
+/// inside the crate. The file is read at runtime, and does not need to exist.
+#[macro_export]
+macro_rules! bundled_input {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt")
+    };
+}
+
+/// Reads the puzzle input.
+///
+/// - `Some("-")` reads from stdin.
+/// - `Some(path)` reads the given file.
+/// - `None` falls back to the `bundled` file, if it exists.
+pub fn read(path: Option<&str>, bundled: &str) -> io::Result<String> {
+    match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("Could not read {}: {}", path, e))),
+        None if Path::new(bundled).exists() => fs::read_to_string(bundled),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No input given, and there is no bundled input at {}. Pass a path, or - to read from stdin.",
+                bundled
+            ),
+        )),
+    }
+}
+
+/// Reads the puzzle input from the path passed as the first command line
+/// argument, falling back to `bundled`. Exits the process if that fails.
+pub fn from_args(bundled: &str) -> String {
+    let path = std::env::args().nth(1);
+
+    read(path.as_deref(), bundled).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    const MISSING: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/does-not-exist.txt");
+
+    #[test]
+    fn test_read_path() {
+        assert!(read(Some(MANIFEST), MISSING)
+            .unwrap()
+            .contains("name = \"aoc-core\""));
+        assert!(read(Some(MISSING), MANIFEST).is_err());
+    }
+
+    #[test]
+    fn test_read_bundled() {
+        assert!(read(None, MANIFEST)
+            .unwrap()
+            .contains("name = \"aoc-core\""));
+        assert_eq!(
+            std::io::ErrorKind::NotFound,
+            read(None, MISSING).unwrap_err().kind()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Shared building blocks for the Advent of Code 2021 solutions: the
+//! [`Solution`] trait every day implements, and helpers to read and parse
+//! puzzle input.
+
+#![warn(missing_docs)]
+
+pub mod input;
+pub mod parse;
+mod solution;
+
+pub use parse::ParseError;
+pub use solution::{run, Solution};
@@ -0,0 +1,136 @@
+//! Errors for puzzle input that can't be parsed, and helpers to point them at
+//! the right spot in the input.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Puzzle input that could not be parsed, along with where and why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    reason: String,
+}
+
+impl ParseError {
+    /// Creates an error at the given line and column, both counting from 1.
+    pub fn new(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error pointing at `part`, which should be a slice of `line`.
+    /// `index` is the index of the line in the input, counting from 0 as
+    /// `enumerate` does.
+    ///
+    /// If `part` is not a slice of `line`, the error points at the start of the
+    /// line.
+    pub fn at(index: usize, line: &str, part: &str, reason: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = match line.get(..offset) {
+            Some(before) if offset + part.len() <= line.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+
+        Self::new(index + 1, column, reason)
+    }
+
+    /// Creates an error just past the end of `input`, for input that stops
+    /// before everything has been read.
+    pub fn end_of_input(input: &str, reason: impl Into<String>) -> Self {
+        let last_line = input.lines().last().unwrap_or_default();
+
+        Self::new(
+            input.lines().count().max(1),
+            last_line.chars().count() + 1,
+            reason,
+        )
+    }
+
+    /// Moves the error to the line with the given `index` (counting from 0),
+    /// for errors created while parsing a single line on its own.
+    pub fn on_line(self, index: usize) -> Self {
+        Self {
+            line: index + 1,
+            ..self
+        }
+    }
+
+    /// The line of the input the error is on, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column (in characters) of the line the error is on, counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Why the input could not be parsed.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `part` of the `index`th line as a number, see [`ParseError::at`].
+pub fn number<T: FromStr>(index: usize, line: &str, part: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| {
+        let reason = if part.is_empty() {
+            "Expected a number".to_owned()
+        } else {
+            format!("Expected a number, found `{}`", part)
+        };
+
+        ParseError::at(index, line, part, reason)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number, ParseError};
+
+    #[test]
+    fn test_at() {
+        let line = "forward 5";
+        assert_eq!(
+            ParseError::new(3, 9, "Oops"),
+            ParseError::at(2, line, &line[8..], "Oops")
+        );
+        assert_eq!(
+            ParseError::new(1, 1, "Oops"),
+            ParseError::at(0, line, "elsewhere", "Oops")
+        );
+        assert_eq!(
+            ParseError::new(2, 5, "Oops"),
+            ParseError::end_of_input("1234\n1234\n", "Oops")
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        let line = "12,x";
+        assert_eq!(Ok(12), number::<u8>(0, line, &line[..2]));
+        assert_eq!(
+            "line 1, column 4: Expected a number, found `x`",
+            number::<u8>(0, line, &line[3..]).unwrap_err().to_string()
+        );
+    }
+}
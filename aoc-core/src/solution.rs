@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// A single day's puzzle: how to parse its input, and how to solve both of its
+/// parts from that parsed input.
+///
+/// Each part returns `None` when the input has no answer, e.g. when no bingo
+/// board ever wins. Days without a second part use [`std::convert::Infallible`]
+/// as their `Part2` answer.
+pub trait Solution {
+    /// Day of the month (1-25).
+    const DAY: u8;
+    /// Title of the puzzle, as shown on the website.
+    const TITLE: &'static str;
+
+    /// The parsed puzzle input, which may borrow from the raw input.
+    type Parsed<'a>;
+    /// The answer to part 1.
+    type Part1: Display;
+    /// The answer to part 2.
+    type Part2: Display;
+
+    /// Parses the raw puzzle input, or explains where it is malformed.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    /// Solves part 1, or returns `None` if there is no answer.
+    fn part1(parsed: &Self::Parsed<'_>) -> Option<Self::Part1>;
+    /// Solves part 2, or returns `None` if there is no answer.
+    fn part2(parsed: &Self::Parsed<'_>) -> Option<Self::Part2>;
+}
+
+/// Runs a single day as its own binary: reads the input from the path passed as
+/// the first command line argument (see [`crate::input::from_args`]), and prints
+/// the answer to both parts. Parts without an answer are printed as `-`.
+///
+/// Exits the process if the input can't be read or parsed.
+pub fn run<S: Solution>(bundled_input: &str) {
+    let input = crate::input::from_args(bundled_input);
+    let parsed = S::parse(&input).unwrap_or_else(|e| {
+        eprintln!("Could not parse the input: {}", e);
+        std::process::exit(1);
+    });
+
+    println!("Day {}: {}", S::DAY, S::TITLE);
+    print_answer(1, S::part1(&parsed));
+    print_answer(2, S::part2(&parsed));
+}
+
+fn print_answer(part: u8, answer: Option<impl Display>) {
+    match answer.map(|answer| answer.to_string()) {
+        // Multi-line answers, such as day 13's folded paper, start on their own
+        // line so they stay aligned.
+        Some(answer) if answer.contains('\n') => println!("Part {}:\n{}", part, answer),
+        Some(answer) => println!("Part {}: {}", part, answer),
+        None => println!("Part {}: -", part),
+    }
+}
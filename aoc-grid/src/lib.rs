@@ -0,0 +1,419 @@
+//! A rectangular grid of cells, for the puzzles that take place on a map.
+
+#![warn(missing_docs)]
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::ParseError;
+
+/// A position on the grid, as `(x, y)`, with `(0, 0)` in the top left.
+pub type Position = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: up, right, down, left.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all 8 surrounding neighbours, clockwise starting at the top.
+const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, given row by row.
+    ///
+    /// ## Panics
+    ///
+    /// If the number of cells does not match the width and height.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid where every cell holds the same value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid of one character per cell. `parse_cell` returns `None` for
+    /// characters that are not allowed. Every line has to be the same length.
+    pub fn parse_with(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let length = line.chars().count();
+            let expected = *width.get_or_insert(length);
+            if length != expected {
+                return Err(ParseError::new(
+                    y + 1,
+                    length.min(expected) + 1,
+                    format!("Line is {} wide, expected {}", length, expected),
+                ));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                match parse_cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        return Err(ParseError::new(
+                            y + 1,
+                            x + 1,
+                            format!("Unexpected character `{}`", c),
+                        ))
+                    }
+                }
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// All cells, row by row, mutably.
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Converts an index into [`Grid::cells`] to a position.
+    pub fn coordinates_from_index(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    /// Converts a position to an index into [`Grid::cells`].
+    #[inline]
+    pub fn index_from_coordinates(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+
+    /// Whether the position lies within the grid.
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at `position`, or `None` if it is outside of the grid.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_from_coordinates(position)])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `position` mutably, or `None` if it is outside of the grid.
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_from_coordinates(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// All cell values, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    /// All cells along with their position, row by row.
+    pub fn iter_with_positions(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells in row `y`, from left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept a size of 0, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells in column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// All columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Moves `delta` away from `position`, if that stays within the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        if self.contains(position) {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    /// Moves `delta` away from `position`, wrapping around the edges as if the
+    /// grid were a torus.
+    pub fn wrapping_offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Position {
+        (
+            (x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// The up to 4 orthogonally adjacent positions: up, right, down, left.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    /// The up to 8 surrounding positions, including diagonals, clockwise
+    /// starting at the top.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    /// Like [`Grid::neighbours4`], but wrapping around the edges.
+    pub fn wrapping_neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |delta| self.wrapping_offset(position, *delta))
+    }
+
+    /// Like [`Grid::neighbours8`], but wrapping around the edges.
+    pub fn wrapping_neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .map(move |delta| self.wrapping_offset(position, *delta))
+    }
+
+    /// Creates a grid of the same size, with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits, such as a height map.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, keeping them as they are.
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(
+            self.contains(position),
+            "Position {:?} is outside of the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+
+        &self.cells[self.index_from_coordinates(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(
+            self.contains(position),
+            "Position {:?} is outside of the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+
+        let index = self.index_from_coordinates(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    const TEST_INPUT: &str = "123
+456";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_digits(TEST_INPUT).unwrap();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(&[1, 2, 3, 4, 5, 6], grid.cells());
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+
+        let grid = Grid::parse_chars(".#\n#.").unwrap();
+        assert_eq!(&['.', '#', '#', '.'], grid.cells());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 2, column 3: Line is 2 wide, expected 3",
+            Grid::parse_chars("...\n..").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 2: Unexpected character `x`",
+            Grid::parse_digits("1x").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let grid = Grid::parse_digits(TEST_INPUT).unwrap();
+        assert_eq!((1, 1), grid.coordinates_from_index(4));
+        assert_eq!(4, grid.index_from_coordinates((1, 1)));
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+            grid.positions().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 1), (0, 1)],
+            grid.neighbours4((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)],
+            grid.neighbours8((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 1), (1, 0), (0, 1), (2, 0)],
+            grid.wrapping_neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.wrapping_neighbours8((2, 1)).count());
+        assert_eq!((0, 0), grid.wrapping_offset((2, 1), (1, 1)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(
+            vec![&[1, 2, 3], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            TEST_INPUT,
+            Grid::parse_digits(TEST_INPUT).unwrap().to_string()
+        );
+        assert_eq!(
+            "#.\n.#",
+            Grid::parse_chars(".#\n#.")
+                .unwrap()
+                .map(|c| if *c == '#' { '.' } else { '#' })
+                .to_string()
+        );
+    }
+}
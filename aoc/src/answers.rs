@@ -0,0 +1,179 @@
+//! The recorded answers to the real puzzle inputs, which `aoc verify` checks
+//! every day against.
+//!
+//! They are stored in a small subset of TOML: a `[dayN]` table per day, with
+//! `part1` and `part2` as basic strings.
+//!
+//! ```toml
+//! [day13]
+//! part1 = "759"
+//! part2 = "#..#.####\n#..#.#...\n"
+//! ```
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use aoc_core::ParseError;
+
+use crate::json;
+
+/// Answers by day and part.
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+/// Reads an answers file written by [`format`].
+pub fn parse(input: &str) -> Result<Answers, ParseError> {
+    let mut answers = Answers::new();
+    let mut day = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(table) = trimmed.strip_prefix('[') {
+            let number = table
+                .strip_suffix(']')
+                .and_then(|table| table.strip_prefix("day"))
+                .ok_or_else(|| {
+                    ParseError::at(index, line, trimmed, "Expected a table such as `[day1]`")
+                })?;
+            day = Some(aoc_core::parse::number(index, line, number)?);
+
+            continue;
+        }
+
+        let (key, value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(index, line, trimmed, "Expected `part1 = \"...\"`"))?;
+        let key = key.trim();
+        let part = match key {
+            "part1" => 1,
+            "part2" => 2,
+            _ => {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    key,
+                    format!("Expected part1 or part2, found `{}`", key),
+                ))
+            }
+        };
+        let day = day.ok_or_else(|| {
+            ParseError::at(
+                index,
+                line,
+                key,
+                "Answers have to be inside a `[dayN]` table",
+            )
+        })?;
+
+        answers.insert((day, part), unquote(index, line, value.trim())?);
+    }
+
+    Ok(answers)
+}
+
+/// Formats the answers as TOML, ordered by day and part.
+pub fn format(answers: &Answers) -> String {
+    let mut result =
+        String::from("# Answers to the bundled puzzle inputs, checked by `aoc verify`.\n");
+    let mut current_day = None;
+
+    for (&(day, part), answer) in answers {
+        if current_day != Some(day) {
+            write!(result, "\n[day{}]\n", day).unwrap();
+            current_day = Some(day);
+        }
+
+        // JSON strings are also valid TOML basic strings
+        writeln!(result, "part{} = {}", part, json::string(answer)).unwrap();
+    }
+
+    result
+}
+
+/// Reads a TOML basic string, which has to be all of `value`.
+fn unquote(index: usize, line: &str, value: &str) -> Result<String, ParseError> {
+    let error = |part: &str, reason: &str| ParseError::at(index, line, part, reason);
+
+    let inner = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| error(value, "Expected a quoted string"))?;
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.char_indices();
+    while let Some((offset, c)) = chars.next() {
+        let rest = &inner[offset..];
+        match c {
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('"') => result.push('"'),
+                Some('\\') => result.push('\\'),
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some('t') => result.push('\t'),
+                Some('u') => {
+                    let hex = rest.get(2..6).unwrap_or_default();
+                    let c = u32::from_str_radix(hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| error(rest, "Expected 4 hexadecimal digits after \\u"))?;
+                    result.push(c);
+                    chars.nth(3);
+                }
+                _ => return Err(error(rest, "Unknown escape sequence")),
+            },
+            '"' => return Err(error(rest, "Unescaped quote inside a string")),
+            c => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse, Answers};
+    use crate::json;
+
+    #[test]
+    fn test_format_and_parse() {
+        let answers = Answers::from([
+            ((13, 2), "#..#\n.##.".to_owned()),
+            ((1, 1), "1228".to_owned()),
+            ((13, 1), "759".to_owned()),
+        ]);
+        let formatted = format(&answers);
+
+        assert!(formatted.contains("\n[day1]\npart1 = \"1228\"\n\n[day13]\npart1 = \"759\"\n"));
+        assert!(formatted.contains("part2 = \"#..#\\n.##.\"\n"));
+        assert_eq!(Ok(answers), parse(&formatted));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 1, column 1: Answers have to be inside a `[dayN]` table",
+            parse("part1 = \"1\"").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 9: Expected a quoted string",
+            parse("[day1]\npart1 = 1228").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 3: Expected part1 or part2, found `part3`",
+            parse("[day1]\n  part3 = \"1\"").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert_eq!(
+            Ok(Answers::from([((1, 1), "a\"b\\c\u{1b}".to_owned())])),
+            parse(&format!(
+                "[day1]\npart1 = {}",
+                json::string("a\"b\\c\u{1b}")
+            ))
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! Repeatable timing of each day's parse and part functions, and comparing two
+//! saved sets of timings to find regressions.
+
+use std::{fmt::Write, time::Duration};
+
+use aoc_core::ParseError;
+
+/// What is being timed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|stage| stage.name() == name)
+    }
+}
+
+/// The time each iteration took, per stage.
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn stage(&self, stage: Stage) -> &[Duration] {
+        match stage {
+            Stage::Parse => &self.parse,
+            Stage::Part1 => &self.part1,
+            Stage::Part2 => &self.part2,
+        }
+    }
+}
+
+/// Summary of the timings of one stage of one day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub day: u8,
+    pub stage: Stage,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises the `durations` of all iterations. There has to be at least
+    /// one.
+    pub fn new(day: u8, stage: Stage, durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+
+        Self {
+            day,
+            stage,
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+const HEADER: &str = "# day\tstage\tmin_ns\tmedian_ns\tmax_ns";
+
+/// Formats results to be saved with `--save`, and read back by [`load`]: a
+/// tab separated line per day and stage, with the times in nanoseconds.
+pub fn save(stats: &[Stats]) -> String {
+    let mut result = String::new();
+    writeln!(result, "{}", HEADER).unwrap();
+    for stats in stats {
+        writeln!(
+            result,
+            "{}\t{}\t{}\t{}\t{}",
+            stats.day,
+            stats.stage.name(),
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos()
+        )
+        .unwrap();
+    }
+
+    result
+}
+
+/// Reads results written by [`save`]. Lines starting with `#` are comments.
+pub fn load(input: &str) -> Result<Vec<Stats>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let fields: Vec<_> = line.split('\t').collect();
+            if fields.len() != 5 {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    line,
+                    format!("Expected 5 tab separated fields, found {}", fields.len()),
+                ));
+            }
+
+            let stage = Stage::from_name(fields[1]).ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    fields[1],
+                    format!("Unknown stage `{}`", fields[1]),
+                )
+            })?;
+            let nanos =
+                |field: &str| aoc_core::parse::number(index, line, field).map(Duration::from_nanos);
+
+            Ok(Stats {
+                day: aoc_core::parse::number(index, line, fields[0])?,
+                stage,
+                min: nanos(fields[2])?,
+                median: nanos(fields[3])?,
+                max: nanos(fields[4])?,
+            })
+        })
+        .collect()
+}
+
+/// How the median time of a stage changed between two runs.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Comparison {
+    /// The relative change, e.g. `0.1` if it got 10% slower.
+    pub fn change(&self) -> f64 {
+        self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0
+    }
+
+    /// Whether this got slower by more than `threshold`, e.g. `0.1` for 10%.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Pairs up the stages that appear in both `before` and `after`.
+pub fn compare(before: &[Stats], after: &[Stats]) -> Vec<Comparison> {
+    after
+        .iter()
+        .filter_map(|after| {
+            before
+                .iter()
+                .find(|before| before.day == after.day && before.stage == after.stage)
+                .map(|before| Comparison {
+                    day: after.day,
+                    stage: after.stage,
+                    before: before.median,
+                    after: after.median,
+                })
+        })
+        .collect()
+}
+
+/// Formats a duration with a unit that keeps it readable, e.g. `1.23 ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, format_duration, load, save, Stage, Stats};
+
+    fn stats(day: u8, stage: Stage, median: u64) -> Stats {
+        Stats {
+            day,
+            stage,
+            min: Duration::from_nanos(median / 2),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(median * 2),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let durations = [5, 1, 4, 2, 3].map(Duration::from_millis);
+
+        assert_eq!(
+            Stats {
+                day: 7,
+                stage: Stage::Part2,
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            },
+            Stats::new(7, Stage::Part2, &durations)
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let results = vec![stats(6, Stage::Part1, 1000), stats(17, Stage::Part2, 30)];
+        let saved = save(&results);
+
+        assert!(saved.contains("17\tpart2\t15\t30\t60\n"));
+        assert_eq!(Ok(results), load(&saved));
+        assert_eq!(
+            "line 1, column 3: Unknown stage `part3`",
+            load("1\tpart3\t1\t2\t3").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = [stats(7, Stage::Part2, 100), stats(17, Stage::Part2, 100)];
+        let after = [
+            stats(7, Stage::Part2, 150),
+            stats(17, Stage::Part2, 105),
+            stats(21, Stage::Part2, 100),
+        ];
+
+        let comparisons = compare(&before, &after);
+        assert_eq!(2, comparisons.len());
+        assert!(comparisons[0].is_regression(0.1));
+        assert!(!comparisons[1].is_regression(0.1));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("999 ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.50 µs", format_duration(Duration::from_nanos(1500)));
+        assert_eq!("12.35 ms", format_duration(Duration::from_micros(12345)));
+        assert_eq!("2.00 s", format_duration(Duration::from_secs(2)));
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{ParseError, Solution};
+
+use crate::bench::Timings;
+
+/// The answer to a single part, and how long it took to solve it.
+pub struct Solved {
+    pub part: u8,
+    /// `None` if the part has no answer.
+    pub answer: Option<String>,
+    /// Time spent solving the part, not including parsing the input.
+    pub elapsed: Duration,
+}
+
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub bundled_input: &'static str,
+    /// Parses the input once, and solves each of the requested parts.
+    pub solve: fn(&str, &[u8]) -> Result<Vec<Solved>, ParseError>,
+    /// Parses the input and solves both parts the given number of times,
+    /// timing each step separately.
+    pub bench: fn(&str, usize) -> Result<Timings, ParseError>,
+}
+
+const fn day<S: Solution>(bundled_input: &'static str) -> Day {
+    Day {
+        day: S::DAY,
+        title: S::TITLE,
+        bundled_input,
+        solve: solve::<S>,
+        bench: bench::<S>,
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Solved>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed).map(|answer| answer.to_string()),
+                2 => S::part2(&parsed).map(|answer| answer.to_string()),
+                _ => None,
+            };
+
+            Solved {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings::default();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        timings.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        timings.part2.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day1_sonar_sweep::SonarSweep>(day1_sonar_sweep::BUNDLED_INPUT),
+    day::<day2_dive::Dive>(day2_dive::BUNDLED_INPUT),
+    day::<day3_binary_diagnostic::BinaryDiagnostic>(day3_binary_diagnostic::BUNDLED_INPUT),
+    day::<day4_giant_squid::GiantSquid>(day4_giant_squid::BUNDLED_INPUT),
+    day::<day5_hydrothermal_venture::HydrothermalVenture>(day5_hydrothermal_venture::BUNDLED_INPUT),
+    day::<day6_lanternfish::Lanternfish>(day6_lanternfish::BUNDLED_INPUT),
+    day::<day7_the_treachery_of_whales::TreacheryOfWhales>(
+        day7_the_treachery_of_whales::BUNDLED_INPUT,
+    ),
+    day::<day8_seven_segment_search::SevenSegmentSearch>(day8_seven_segment_search::BUNDLED_INPUT),
+    day::<day9_smoke_basin::SmokeBasin>(day9_smoke_basin::BUNDLED_INPUT),
+    day::<day10_syntax_scoring::SyntaxScoring>(day10_syntax_scoring::BUNDLED_INPUT),
+    day::<day11_dumbo_octopus::DumboOctopus>(day11_dumbo_octopus::BUNDLED_INPUT),
+    day::<day12_passage_pathing::PassagePathing>(day12_passage_pathing::BUNDLED_INPUT),
+    day::<day13_transparent_origami::TransparentOrigami>(day13_transparent_origami::BUNDLED_INPUT),
+    day::<day14_extended_polymerization::ExtendedPolymerization>(
+        day14_extended_polymerization::BUNDLED_INPUT,
+    ),
+    day::<day15_chiton::Chiton>(day15_chiton::BUNDLED_INPUT),
+    day::<day16_packet_decoder::PacketDecoder>(day16_packet_decoder::BUNDLED_INPUT),
+    day::<day17_trick_shot::TrickShot>(day17_trick_shot::BUNDLED_INPUT),
+    day::<day18_snailfish::Snailfish>(day18_snailfish::BUNDLED_INPUT),
+    day::<day19_beacon_scanner::BeaconScanner>(day19_beacon_scanner::BUNDLED_INPUT),
+    day::<day20_trench_map::TrenchMap>(day20_trench_map::BUNDLED_INPUT),
+    day::<day21_dirac_dice::DiracDice>(day21_dirac_dice::BUNDLED_INPUT),
+    day::<day22_reactor_reboot::ReactorReboot>(day22_reactor_reboot::BUNDLED_INPUT),
+    day::<day23_amphipod::Amphipod>(day23_amphipod::BUNDLED_INPUT),
+    day::<day24_alu::ArithmeticLogicUnit>(day24_alu::BUNDLED_INPUT),
+    day::<day25_sea_cucumber::SeaCucumber>(day25_sea_cucumber::BUNDLED_INPUT),
+];
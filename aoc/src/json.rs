@@ -0,0 +1,68 @@
+//! Just enough JSON to print the runner's results, without pulling in a
+//! serialisation framework.
+
+use std::fmt::Write;
+
+/// Quotes and escapes `value` as a JSON string.
+pub fn string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+
+    result
+}
+
+/// Formats an array of already encoded values.
+pub fn array(values: impl IntoIterator<Item = String>) -> String {
+    format!("[{}]", values.into_iter().collect::<Vec<_>>().join(", "))
+}
+
+/// Formats an object on a single line, from its keys and already encoded
+/// values.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|(key, value)| format!("{}: {}", string(key), value))
+        .collect();
+
+    format!("{{{}}}", fields.join(", "))
+}
+
+/// Encodes an optional value, using `null` for `None`.
+pub fn nullable<T>(value: Option<T>, encode: impl FnOnce(T) -> String) -> String {
+    value.map_or_else(|| "null".to_owned(), encode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{array, nullable, object, string};
+
+    #[test]
+    fn test_string() {
+        assert_eq!(r#""Dive!""#, string("Dive!"));
+        assert_eq!(r##""#..#\n\"\\\u0007""##, string("#..#\n\"\\\u{7}"));
+    }
+
+    #[test]
+    fn test_object() {
+        assert_eq!(
+            r##"{"day": 13, "answer": null, "grid": ["#.", ".#"]}"##,
+            object(&[
+                ("day", 13.to_string()),
+                ("answer", nullable(None::<&str>, string)),
+                ("grid", array(["#.", ".#"].map(string))),
+            ])
+        );
+    }
+}
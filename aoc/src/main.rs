@@ -0,0 +1,806 @@
+mod answers;
+mod bench;
+mod days;
+mod json;
+
+use std::{fs, io, path::Path, process::ExitCode};
+
+use bench::{Comparison, Stage, Stats};
+use days::{Day, Solved, DAYS};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part 1|2] [--input <path>|-] [--format text|json]
+    aoc run --all [--format text|json]
+    aoc bench <day> [--input <path>|-] [--iterations <n>] [--save <path>]
+    aoc bench --all [--iterations <n>] [--save <path>]
+    aoc compare <before> <after> [--threshold <percent>]
+    aoc verify [<day>] [--answers <path>] [--record]
+
+Without --input, each day reads the input.txt bundled with its crate.
+
+bench times parsing and both parts separately, 10 iterations by default.
+compare reads two files saved by bench --save, and fails if any median got
+slower by more than the threshold (10% by default).
+
+verify solves every day (or just <day>) that has a bundled input, and fails if
+an answer differs from the one recorded in answers.toml at the root of the
+workspace. --record writes the current answers to that file instead.";
+
+/// The answers file checked by `aoc verify`, unless `--answers` is given.
+const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match parse_args(&args) {
+        Ok(Command::RunDay {
+            day,
+            part,
+            input,
+            format,
+        }) => run_day(day, part, input.as_deref(), format),
+        Ok(Command::RunAll { format }) => {
+            run_all(format);
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Bench {
+            day,
+            input,
+            iterations,
+            save,
+        }) => run_bench(day, input.as_deref(), iterations, save.as_deref()),
+        Ok(Command::Compare {
+            before,
+            after,
+            threshold,
+        }) => run_compare(&before, &after, threshold),
+        Ok(Command::Verify {
+            day,
+            answers,
+            record,
+        }) => run_verify(day, answers.as_deref().unwrap_or(ANSWERS), record),
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    RunDay {
+        day: u8,
+        part: Option<u8>,
+        input: Option<String>,
+        format: Format,
+    },
+    RunAll {
+        format: Format,
+    },
+    Bench {
+        /// `None` to benchmark every day.
+        day: Option<u8>,
+        input: Option<String>,
+        iterations: usize,
+        save: Option<String>,
+    },
+    Compare {
+        before: String,
+        after: String,
+        /// How much slower a median may get before it counts as a regression,
+        /// e.g. `0.1` for 10%.
+        threshold: f64,
+    },
+    Verify {
+        /// `None` to verify every day.
+        day: Option<u8>,
+        answers: Option<String>,
+        record: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    /// Human readable text, or a table for `--all`.
+    Text,
+    /// A JSON array with an object per day and part.
+    Json,
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+    match args.next() {
+        Some("run") => parse_run_args(args),
+        Some("bench") => parse_bench_args(args),
+        Some("compare") => parse_compare_args(args),
+        Some("verify") => parse_verify_args(args),
+        Some(other) => Err(format!("Unknown command: {}", other)),
+        None => Err("Missing command".to_owned()),
+    }
+}
+
+fn parse_run_args<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut all = false;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => all = true,
+            "--input" => match args.next() {
+                Some(path) => input = Some(path.to_owned()),
+                None => return Err("--input must be followed by a path, or -".to_owned()),
+            },
+            "--part" => {
+                part = match args.next() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => return Err("--part must be followed by 1 or 2".to_owned()),
+                }
+            }
+            "--format" => {
+                format = match args.next() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format must be followed by text or json".to_owned()),
+                }
+            }
+            _ => day = Some(arg.parse().map_err(|_| format!("Invalid day: {}", arg))?),
+        }
+    }
+
+    match (day, all) {
+        (Some(day), false) => Ok(Command::RunDay {
+            day,
+            part,
+            input,
+            format,
+        }),
+        (None, true) if part.is_none() && input.is_none() => Ok(Command::RunAll { format }),
+        (None, true) => Err("--part and --input cannot be combined with --all".to_owned()),
+        (Some(_), true) => Err("Either pass a day or --all, not both".to_owned()),
+        (None, false) => Err("Missing day".to_owned()),
+    }
+}
+
+fn parse_bench_args<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut iterations = 10;
+    let mut save = None;
+    let mut all = false;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => all = true,
+            "--input" => match args.next() {
+                Some(path) => input = Some(path.to_owned()),
+                None => return Err("--input must be followed by a path, or -".to_owned()),
+            },
+            "--iterations" => {
+                iterations = match args.next().and_then(|n| n.parse().ok()) {
+                    Some(n) if n > 0 => n,
+                    _ => {
+                        return Err("--iterations must be followed by a positive number".to_owned())
+                    }
+                }
+            }
+            "--save" => match args.next() {
+                Some(path) => save = Some(path.to_owned()),
+                None => return Err("--save must be followed by a path".to_owned()),
+            },
+            _ => day = Some(arg.parse().map_err(|_| format!("Invalid day: {}", arg))?),
+        }
+    }
+
+    match (day, all) {
+        (Some(_), true) => Err("Either pass a day or --all, not both".to_owned()),
+        (None, false) => Err("Missing day".to_owned()),
+        (None, true) if input.is_some() => Err("--input cannot be combined with --all".to_owned()),
+        _ => Ok(Command::Bench {
+            day,
+            input,
+            iterations,
+            save,
+        }),
+    }
+}
+
+fn parse_compare_args<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut files = vec![];
+    let mut threshold = 0.1;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--threshold" => {
+                threshold = match args.next().and_then(|n| n.parse::<f64>().ok()) {
+                    Some(percent) if percent >= 0.0 => percent / 100.0,
+                    _ => return Err("--threshold must be followed by a percentage".to_owned()),
+                }
+            }
+            _ => files.push(arg.to_owned()),
+        }
+    }
+
+    match <[String; 2]>::try_from(files) {
+        Ok([before, after]) => Ok(Command::Compare {
+            before,
+            after,
+            threshold,
+        }),
+        Err(_) => Err("compare needs two files: before and after".to_owned()),
+    }
+}
+
+fn parse_verify_args<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut day = None;
+    let mut answers = None;
+    let mut record = false;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--record" => record = true,
+            "--answers" => match args.next() {
+                Some(path) => answers = Some(path.to_owned()),
+                None => return Err("--answers must be followed by a path".to_owned()),
+            },
+            _ => day = Some(arg.parse().map_err(|_| format!("Invalid day: {}", arg))?),
+        }
+    }
+
+    Ok(Command::Verify {
+        day,
+        answers,
+        record,
+    })
+}
+
+fn run_day(day: u8, part: Option<u8>, input: Option<&str>, format: Format) -> ExitCode {
+    let day = match days::find(day) {
+        Some(day) => day,
+        None => {
+            eprintln!("Day {} is not part of this workspace", day);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    let results = match solve(day, input, &parts) {
+        Ok(results) => results,
+        Err(message) => {
+            eprintln!("Day {}: {}", day.day, message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        Format::Text => {
+            println!("Day {}: {}", day.day, day.title);
+            for solved in results {
+                println!(
+                    "Part {}: {}",
+                    solved.part,
+                    solved.answer.as_deref().unwrap_or("-")
+                );
+            }
+        }
+        Format::Json => print_json_array(
+            results
+                .iter()
+                .map(|solved| json_result(day.day, solved))
+                .collect(),
+        ),
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Reads the input for `day`, and solves the given parts.
+///
+/// ## Returns
+///
+/// The answers, or a message explaining why the input could not be read or
+/// parsed.
+fn solve(day: &Day, input: Option<&str>, parts: &[u8]) -> Result<Vec<Solved>, String> {
+    let input = aoc_core::input::read(input, day.bundled_input).map_err(|e| e.to_string())?;
+
+    (day.solve)(&input, parts).map_err(|e| format!("could not parse the input: {}", e))
+}
+
+fn run_all(format: Format) {
+    let results: Vec<_> = DAYS
+        .iter()
+        .map(|day| {
+            let result = solve(day, None, &[1, 2]);
+            if let Err(message) = &result {
+                eprintln!("Day {}: {}", day.day, message);
+            }
+
+            (day, result)
+        })
+        .collect();
+
+    match format {
+        Format::Text => print_table(&results),
+        Format::Json => print_json_array(
+            results
+                .iter()
+                .flat_map(|(day, result)| match result {
+                    Ok(results) => results
+                        .iter()
+                        .map(|solved| json_result(day.day, solved))
+                        .collect(),
+                    Err(message) => [1, 2]
+                        .iter()
+                        .map(|part| json_error(day.day, *part, message))
+                        .collect::<Vec<_>>(),
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn run_bench(
+    day: Option<u8>,
+    input: Option<&str>,
+    iterations: usize,
+    save: Option<&str>,
+) -> ExitCode {
+    let days: Vec<&Day> = match day {
+        Some(day) => match days::find(day) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not part of this workspace", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+
+    let print_row = |day: &str, stage: &str, min: &str, median: &str, max: &str| {
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+            day, stage, min, median, max
+        )
+    };
+
+    print_row("Day", "Stage", "Min", "Median", "Max");
+    let mut results = vec![];
+    let mut failed = false;
+    for day in days {
+        let timings = aoc_core::input::read(input, day.bundled_input)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                (day.bench)(&input, iterations)
+                    .map_err(|e| format!("could not parse the input: {}", e))
+            });
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(message) => {
+                eprintln!("Day {}: {}", day.day, message);
+                failed = true;
+                continue;
+            }
+        };
+
+        for stage in Stage::ALL {
+            let stats = Stats::new(day.day, stage, timings.stage(stage));
+            print_row(
+                &day.day.to_string(),
+                stage.name(),
+                &bench::format_duration(stats.min),
+                &bench::format_duration(stats.median),
+                &bench::format_duration(stats.max),
+            );
+            results.push(stats);
+        }
+    }
+
+    if let Some(path) = save {
+        if let Err(e) = fs::write(path, bench::save(&results)) {
+            eprintln!("Could not save the results to {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_compare(before: &str, after: &str, threshold: f64) -> ExitCode {
+    let load = |path: &str| {
+        let input =
+            fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?;
+
+        bench::load(&input).map_err(|e| format!("Could not parse {}: {}", path, e))
+    };
+    let (before, after) = match (load(before), load(after)) {
+        (Ok(before), Ok(after)) => (before, after),
+        (Err(message), _) | (_, Err(message)) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let comparisons = bench::compare(&before, &after);
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}",
+        "Day", "Stage", "Before", "After", "Change"
+    );
+    for comparison in &comparisons {
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>+7.1}%{}",
+            comparison.day,
+            comparison.stage.name(),
+            bench::format_duration(comparison.before),
+            bench::format_duration(comparison.after),
+            comparison.change() * 100.0,
+            if comparison.is_regression(threshold) {
+                "  regression"
+            } else {
+                ""
+            }
+        );
+    }
+
+    let regressions: Vec<&Comparison> = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .collect();
+    if regressions.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!(
+            "{} of {} medians got more than {}% slower",
+            regressions.len(),
+            comparisons.len(),
+            threshold * 100.0
+        );
+        ExitCode::FAILURE
+    }
+}
+
+fn run_verify(day: Option<u8>, path: &str, record: bool) -> ExitCode {
+    let days: Vec<&Day> = match day {
+        Some(day) => match days::find(day) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not part of this workspace", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+
+    let mut recorded = match fs::read_to_string(path) {
+        Ok(input) => match answers::parse(&input) {
+            Ok(recorded) => recorded,
+            Err(e) => {
+                eprintln!("Could not parse {}: {}", path, e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(e) if record && e.kind() == io::ErrorKind::NotFound => answers::Answers::new(),
+        Err(e) => {
+            eprintln!("Could not read {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    let mut differences = 0;
+    for day in days {
+        // Not every day has its puzzle input checked in, and without it there
+        // is nothing to verify.
+        if !Path::new(day.bundled_input).exists() {
+            println!("{:>3}  skipped, there is no bundled input", day.day);
+            continue;
+        }
+
+        let results = match solve(day, None, &[1, 2]) {
+            Ok(results) => results,
+            Err(message) => {
+                eprintln!("Day {}: {}", day.day, message);
+                failed = true;
+                continue;
+            }
+        };
+
+        for solved in results {
+            let key = (day.day, solved.part);
+            let expected = recorded.get(&key).map(String::as_str);
+            let actual = solved.answer.as_deref();
+            if expected == actual {
+                if actual.is_some() {
+                    println!("{:>3}  part {}  ok", day.day, solved.part);
+                }
+                continue;
+            }
+
+            let describe = |answer: Option<&str>| answer.map_or("nothing".to_owned(), json::string);
+            if record {
+                println!(
+                    "{:>3}  part {}  recorded {}, was {}",
+                    day.day,
+                    solved.part,
+                    describe(actual),
+                    describe(expected)
+                );
+                match solved.answer {
+                    Some(answer) => recorded.insert(key, answer),
+                    None => recorded.remove(&key),
+                };
+            } else {
+                println!(
+                    "{:>3}  part {}  expected {}, got {}",
+                    day.day,
+                    solved.part,
+                    describe(expected),
+                    describe(actual)
+                );
+                differences += 1;
+            }
+        }
+    }
+
+    if record {
+        if let Err(e) = fs::write(path, answers::format(&recorded)) {
+            eprintln!("Could not write the answers to {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    } else if differences > 0 {
+        eprintln!(
+            "{} answers differ from {}, run with --record if that is expected",
+            differences, path
+        );
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_table(results: &[(&Day, Result<Vec<Solved>, String>)]) {
+    let rows: Vec<_> = results
+        .iter()
+        .map(|(day, result)| {
+            let answer = |part: usize| {
+                result
+                    .as_ref()
+                    .ok()
+                    .and_then(|results| results[part].answer.clone())
+                    .unwrap_or_else(|| "-".to_owned())
+            };
+
+            (day, [answer(0), answer(1)])
+        })
+        .collect();
+    let title_width = DAYS.iter().map(|day| day.title.len()).max().unwrap_or(0);
+    let part1_width = rows
+        .iter()
+        .flat_map(|(_, [part1, _])| part1.lines())
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+        .max("Part 1".len());
+
+    let print_row = |number: &str, title: &str, part1: &str, part2: &str| {
+        println!(
+            "{:>3}  {:<title_width$}  {:<part1_width$}  {}",
+            number,
+            title,
+            part1,
+            part2,
+            title_width = title_width,
+            part1_width = part1_width
+        )
+    };
+
+    print_row("Day", "Title", "Part 1", "Part 2");
+    for (day, [part1, part2]) in rows {
+        // Multi-line answers (such as day 13's folded paper) continue on the
+        // following lines, aligned with their column.
+        let part1_lines: Vec<_> = part1.lines().collect();
+        let part2_lines: Vec<_> = part2.lines().collect();
+
+        for line in 0..part1_lines.len().max(part2_lines.len()) {
+            let (number, title) = if line == 0 {
+                (day.day.to_string(), day.title)
+            } else {
+                (String::new(), "")
+            };
+
+            print_row(
+                &number,
+                title,
+                part1_lines.get(line).unwrap_or(&""),
+                part2_lines.get(line).unwrap_or(&""),
+            );
+        }
+    }
+}
+
+/// Prints the entries of a JSON array, one per line.
+fn print_json_array(entries: Vec<String>) {
+    if entries.is_empty() {
+        println!("[]");
+    } else {
+        println!("[\n  {}\n]", entries.join(",\n  "));
+    }
+}
+
+/// Formats the answer to one part as a JSON object. Multi-line answers, such as
+/// day 13's folded paper, also come with their lines as a `grid` array.
+fn json_result(day: u8, solved: &Solved) -> String {
+    let mut fields = vec![
+        ("day", day.to_string()),
+        ("part", solved.part.to_string()),
+        (
+            "answer",
+            json::nullable(solved.answer.as_deref(), json::string),
+        ),
+        ("elapsed_ns", solved.elapsed.as_nanos().to_string()),
+    ];
+
+    if let Some(answer) = solved.answer.as_deref().filter(|a| a.contains('\n')) {
+        fields.push(("grid", json::array(answer.lines().map(json::string))));
+    }
+
+    json::object(&fields)
+}
+
+/// Formats a part that could not be solved as a JSON object, with a `null`
+/// answer and the reason as `error`.
+fn json_error(day: u8, part: u8, message: &str) -> String {
+    json::object(&[
+        ("day", day.to_string()),
+        ("part", part.to_string()),
+        ("answer", "null".to_owned()),
+        ("elapsed_ns", "null".to_owned()),
+        ("error", json::string(message)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{json_result, parse_args, Command, Format, Solved};
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            Ok(Command::RunDay {
+                day: 10,
+                part: None,
+                input: None,
+                format: Format::Text
+            }),
+            parse_args(&args("run 10"))
+        );
+        assert_eq!(
+            Ok(Command::RunDay {
+                day: 6,
+                part: Some(2),
+                input: Some("-".to_owned()),
+                format: Format::Json
+            }),
+            parse_args(&args("run 6 --part 2 --input - --format json"))
+        );
+        assert_eq!(
+            Ok(Command::RunAll {
+                format: Format::Text
+            }),
+            parse_args(&args("run --all"))
+        );
+        assert_eq!(
+            Ok(Command::RunAll {
+                format: Format::Json
+            }),
+            parse_args(&args("run --all --format json"))
+        );
+        assert!(parse_args(&args("run --all --format yaml")).is_err());
+        assert!(parse_args(&args("run --all --part 1")).is_err());
+        assert!(parse_args(&args("run --all --input input.txt")).is_err());
+        assert!(parse_args(&args("run 1 --input")).is_err());
+        assert!(parse_args(&args("run 3 --part 3")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 1 --iterations 5")).is_err());
+        assert!(parse_args(&args("walk 1")).is_err());
+    }
+
+    #[test]
+    fn test_json_result() {
+        assert_eq!(
+            r#"{"day": 1, "part": 2, "answer": "1257", "elapsed_ns": 1500}"#,
+            json_result(
+                1,
+                &Solved {
+                    part: 2,
+                    answer: Some("1257".to_owned()),
+                    elapsed: Duration::from_nanos(1500),
+                }
+            )
+        );
+        assert_eq!(
+            r##"{"day": 13, "part": 2, "answer": "#.\n.#", "elapsed_ns": 0, "grid": ["#.", ".#"]}"##,
+            json_result(
+                13,
+                &Solved {
+                    part: 2,
+                    answer: Some("#.\n.#".to_owned()),
+                    elapsed: Duration::ZERO,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            Ok(Command::Bench {
+                day: Some(17),
+                input: None,
+                iterations: 10,
+                save: None
+            }),
+            parse_args(&args("bench 17"))
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                day: None,
+                input: None,
+                iterations: 3,
+                save: Some("before.tsv".to_owned())
+            }),
+            parse_args(&args("bench --all --iterations 3 --save before.tsv"))
+        );
+        assert!(parse_args(&args("bench --all --input input.txt")).is_err());
+        assert!(parse_args(&args("bench 7 --iterations 0")).is_err());
+        assert!(parse_args(&args("bench 7 --format json")).is_err());
+    }
+
+    #[test]
+    fn test_parse_compare_args() {
+        assert_eq!(
+            Ok(Command::Compare {
+                before: "before.tsv".to_owned(),
+                after: "after.tsv".to_owned(),
+                threshold: 0.25
+            }),
+            parse_args(&args("compare before.tsv after.tsv --threshold 25"))
+        );
+        assert!(parse_args(&args("compare before.tsv")).is_err());
+        assert!(parse_args(&args("compare a b c")).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        assert_eq!(
+            Ok(Command::Verify {
+                day: None,
+                answers: None,
+                record: false
+            }),
+            parse_args(&args("verify"))
+        );
+        assert_eq!(
+            Ok(Command::Verify {
+                day: Some(13),
+                answers: Some("answers.toml".to_owned()),
+                record: true
+            }),
+            parse_args(&args("verify 13 --record --answers answers.toml"))
+        );
+        assert!(parse_args(&args("verify --answers")).is_err());
+        assert!(parse_args(&args("verify --all")).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+//! Day 1: Sonar Sweep.
+//!
+//! Counts how often the depth readings of a sonar sweep increase, either from
+//! one reading to the next, or between sliding windows of readings.
+
+#![warn(missing_docs)]
+
+pub mod report;
+pub mod window;
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 1.
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Parsed<'a> = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        parse_readings(input)
+    }
+
+    fn part1(readings: &Vec<u32>) -> Option<usize> {
+        Some(count_increases(readings))
+    }
+
+    fn part2(readings: &Vec<u32>) -> Option<usize> {
+        Some(count_window_increases(readings, 3))
+    }
+}
+
+/// Parses one depth reading per line.
+pub fn parse_readings(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| aoc_core::parse::number(index, line, line))
+        .collect()
+}
+
+/// Counts the readings that are deeper than the one before them.
+pub fn count_increases(readings: &[u32]) -> usize {
+    readings.windows(2).filter(|pair| pair[1] > pair[0]).count()
+}
+
+/// Counts how often the sum of a sliding window of `window_size` readings
+/// increases compared to the previous window.
+///
+/// ## Panics
+///
+/// If `window_size` is 0.
+pub fn count_window_increases(readings: &[u32], window_size: usize) -> usize {
+    window::analyze(readings.iter().copied(), window_size).increases
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+#[test]
+fn test_part1() {
+    assert_eq!(7, count_increases(&parse_readings(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        5,
+        count_window_increases(&parse_readings(TEST_INPUT).unwrap(), 3)
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 2, column 1: Expected a number, found `2OO`",
+        parse_readings("199\n2OO").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,289 @@
+//! Summarizes a sonar sweep: its extremes, a moving average, and readings that
+//! stand out from the ones just before them.
+
+use std::fmt::Write;
+
+/// What to put in a [`Report`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReportOptions {
+    /// The number of readings to average, and to compare each reading with.
+    pub window_size: usize,
+    /// Flags readings further than this from the average of the window before
+    /// them.
+    pub threshold: Option<f64>,
+    /// Flags readings further than this many standard deviations from the
+    /// average of the window before them.
+    pub deviations: Option<f64>,
+}
+
+/// A reading, along with where it is in the sweep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reading {
+    /// The index of the reading, counting from 0.
+    pub index: usize,
+    /// The depth.
+    pub depth: u32,
+}
+
+/// A reading that differs a lot from the trailing window, the readings just
+/// before it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anomaly {
+    /// The reading that stands out.
+    pub reading: Reading,
+    /// How much deeper the reading is than the average of the trailing window
+    /// (negative if it's shallower).
+    pub difference: f64,
+    /// The standard deviation of the trailing window.
+    pub standard_deviation: f64,
+}
+
+/// A summary of a sweep.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The number of readings in the window.
+    pub window_size: usize,
+    /// The shallowest reading, the first one if there are several.
+    pub min: Reading,
+    /// The deepest reading, the first one if there are several.
+    pub max: Reading,
+    /// The average of every full window, starting with the one that ends at
+    /// index `window_size - 1`.
+    pub moving_averages: Vec<f64>,
+    /// The flagged readings, in order.
+    pub anomalies: Vec<Anomaly>,
+}
+
+/// The average and (population) standard deviation of the readings.
+fn mean_and_standard_deviation(readings: &[u32]) -> (f64, f64) {
+    let count = readings.len() as f64;
+    let mean = readings.iter().map(|&depth| depth as f64).sum::<f64>() / count;
+    let variance = readings
+        .iter()
+        .map(|&depth| (depth as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+
+    (mean, variance.sqrt())
+}
+
+/// Summarizes the sweep.
+///
+/// ## Returns
+///
+/// The report, or `None` if there are no readings.
+///
+/// ## Panics
+///
+/// If the window size is 0.
+pub fn report(readings: &[u32], options: &ReportOptions) -> Option<Report> {
+    assert!(options.window_size > 0, "Windows need at least one reading");
+
+    let reading = |(index, &depth)| Reading { index, depth };
+    // `min_by_key` and `max_by_key` disagree on which of several equal
+    // elements they return, so compare by index as well
+    let min = readings
+        .iter()
+        .enumerate()
+        .min_by_key(|&(index, &depth)| (depth, index))
+        .map(reading)?;
+    let max = readings
+        .iter()
+        .enumerate()
+        .max_by_key(|&(index, &depth)| (depth, usize::MAX - index))
+        .map(reading)?;
+
+    let moving_averages = readings
+        .windows(options.window_size)
+        .map(|window| mean_and_standard_deviation(window).0)
+        .collect();
+
+    let anomalies = readings
+        .windows(options.window_size + 1)
+        .enumerate()
+        .filter_map(|(start, window)| {
+            let (trailing, depth) = window.split_at(options.window_size);
+            let (mean, standard_deviation) = mean_and_standard_deviation(trailing);
+            let difference = depth[0] as f64 - mean;
+
+            let beyond_threshold = options
+                .threshold
+                .is_some_and(|threshold| difference.abs() > threshold);
+            let beyond_deviations = options
+                .deviations
+                .is_some_and(|deviations| difference.abs() > deviations * standard_deviation);
+
+            (beyond_threshold || beyond_deviations).then_some(Anomaly {
+                reading: Reading {
+                    index: start + options.window_size,
+                    depth: depth[0],
+                },
+                difference,
+                standard_deviation,
+            })
+        })
+        .collect();
+
+    Some(Report {
+        window_size: options.window_size,
+        min,
+        max,
+        moving_averages,
+        anomalies,
+    })
+}
+
+/// Renders the report for people to read.
+pub fn as_text(report: &Report) -> String {
+    let mut text = String::new();
+    writeln!(
+        text,
+        "Shallowest: {} at index {}",
+        report.min.depth, report.min.index
+    )
+    .unwrap();
+    writeln!(
+        text,
+        "Deepest: {} at index {}",
+        report.max.depth, report.max.index
+    )
+    .unwrap();
+
+    let averages: Vec<_> = report
+        .moving_averages
+        .iter()
+        .map(|average| format!("{:.2}", average))
+        .collect();
+    writeln!(
+        text,
+        "Moving average of {}: {}",
+        report.window_size,
+        averages.join(", ")
+    )
+    .unwrap();
+
+    if report.anomalies.is_empty() {
+        writeln!(text, "No anomalies").unwrap();
+    } else {
+        writeln!(text, "Anomalies:").unwrap();
+    }
+    for anomaly in &report.anomalies {
+        writeln!(
+            text,
+            "  {} at index {}: {:+.2} from the trailing average, standard deviation {:.2}",
+            anomaly.reading.depth,
+            anomaly.reading.index,
+            anomaly.difference,
+            anomaly.standard_deviation
+        )
+        .unwrap();
+    }
+
+    text
+}
+
+/// Renders the report as CSV, with one row per reading. The moving average is
+/// that of the window ending at the reading, and is left empty until the
+/// window is full.
+pub fn as_csv(readings: &[u32], report: &Report) -> String {
+    let mut csv = "index,depth,moving_average,anomaly\n".to_owned();
+    let mut anomalies = report.anomalies.iter().peekable();
+
+    for (index, depth) in readings.iter().enumerate() {
+        let average = (index + 1)
+            .checked_sub(report.window_size)
+            .and_then(|start| report.moving_averages.get(start))
+            .map_or(String::new(), |average| format!("{:.2}", average));
+        let anomaly = anomalies
+            .next_if(|anomaly| anomaly.reading.index == index)
+            .is_some();
+
+        writeln!(csv, "{},{},{},{}", index, depth, average, anomaly).unwrap();
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_readings,
+        report::{as_csv, as_text, report, Reading, ReportOptions},
+        TEST_INPUT,
+    };
+
+    fn flagged(threshold: Option<f64>, deviations: Option<f64>) -> Vec<usize> {
+        let options = ReportOptions {
+            window_size: 3,
+            threshold,
+            deviations,
+        };
+
+        report(&parse_readings(TEST_INPUT).unwrap(), &options)
+            .unwrap()
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.reading.index)
+            .collect()
+    }
+
+    #[test]
+    fn extremes() {
+        let options = ReportOptions {
+            window_size: 2,
+            threshold: None,
+            deviations: None,
+        };
+        let report = report(&[5, 3, 8, 3, 8], &options).unwrap();
+
+        assert_eq!(Reading { index: 1, depth: 3 }, report.min);
+        assert_eq!(Reading { index: 2, depth: 8 }, report.max);
+        assert_eq!(vec![4.0, 5.5, 5.5, 5.5], report.moving_averages);
+        assert!(report.anomalies.is_empty());
+    }
+
+    #[test]
+    fn anomalies() {
+        assert_eq!(vec![6, 7, 8], flagged(Some(20.0), None));
+        assert_eq!(vec![6, 7], flagged(None, Some(3.0)));
+        assert_eq!(vec![3, 6, 7], flagged(None, Some(1.5)));
+        assert_eq!(vec![3, 6, 7, 8], flagged(Some(20.0), Some(1.5)));
+        assert!(flagged(None, None).is_empty());
+    }
+
+    #[test]
+    fn output() {
+        let readings = parse_readings(TEST_INPUT).unwrap();
+        let options = ReportOptions {
+            window_size: 3,
+            threshold: Some(50.0),
+            deviations: None,
+        };
+        let report = report(&readings, &options).unwrap();
+
+        assert_eq!(
+            "Shallowest: 199 at index 0
+Deepest: 269 at index 7
+Moving average of 3: 202.33, 206.00, 206.00, 205.67, 215.67, 238.67, 256.33, 264.00
+Anomalies:
+  269 at index 7: +53.33 from the trailing average, standard deviation 17.44
+",
+            as_text(&report)
+        );
+        assert_eq!(
+            "index,depth,moving_average,anomaly
+0,199,,false
+1,200,,false
+2,208,202.33,false
+3,210,206.00,false
+4,200,206.00,false
+5,207,205.67,false
+6,240,215.67,false
+7,269,238.67,true
+8,260,256.33,false
+9,263,264.00,false
+",
+            as_csv(&readings, &report)
+        );
+    }
+}
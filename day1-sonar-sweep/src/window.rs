@@ -0,0 +1,195 @@
+//! Compares sliding windows of readings in a single pass, keeping only the
+//! current window in memory, so sweeps of any length can be streamed in.
+
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+
+use aoc_core::ParseError;
+
+/// How the sum of each window compares to the window before it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowStats {
+    /// Windows deeper than the previous one.
+    pub increases: usize,
+    /// Windows shallower than the previous one.
+    pub decreases: usize,
+    /// Windows as deep as the previous one.
+    pub plateaus: usize,
+    /// The most increases in a row.
+    pub longest_increasing_run: usize,
+}
+
+/// Feeds readings one at a time through a sliding window, and keeps count of
+/// how the windows compare.
+#[derive(Clone, Debug)]
+pub struct WindowAnalyzer {
+    window_size: usize,
+    window: VecDeque<u32>,
+    sum: u64,
+    previous_sum: Option<u64>,
+    current_run: usize,
+    stats: WindowStats,
+}
+
+impl WindowAnalyzer {
+    /// Creates an analyzer for windows of `window_size` readings.
+    ///
+    /// ## Panics
+    ///
+    /// If `window_size` is 0.
+    pub fn new(window_size: usize) -> Self {
+        assert!(window_size > 0, "Windows need at least one reading");
+
+        Self {
+            window_size,
+            window: VecDeque::with_capacity(window_size),
+            sum: 0,
+            previous_sum: None,
+            current_run: 0,
+            stats: WindowStats::default(),
+        }
+    }
+
+    /// Slides the window along to the next reading. Until the window is full,
+    /// there is nothing to compare.
+    pub fn push(&mut self, reading: u32) {
+        self.window.push_back(reading);
+        self.sum += reading as u64;
+        if self.window.len() > self.window_size {
+            self.sum -= self.window.pop_front().unwrap() as u64;
+        }
+        if self.window.len() < self.window_size {
+            return;
+        }
+
+        if let Some(previous_sum) = self.previous_sum {
+            if self.sum > previous_sum {
+                self.stats.increases += 1;
+                self.current_run += 1;
+                self.stats.longest_increasing_run =
+                    self.stats.longest_increasing_run.max(self.current_run);
+            } else {
+                if self.sum < previous_sum {
+                    self.stats.decreases += 1;
+                } else {
+                    self.stats.plateaus += 1;
+                }
+                self.current_run = 0;
+            }
+        }
+        self.previous_sum = Some(self.sum);
+    }
+
+    /// The counts for the readings so far.
+    pub fn stats(&self) -> WindowStats {
+        self.stats
+    }
+}
+
+/// Compares every window of `window_size` readings with the one before it.
+///
+/// ## Panics
+///
+/// If `window_size` is 0.
+pub fn analyze(readings: impl IntoIterator<Item = u32>, window_size: usize) -> WindowStats {
+    let mut analyzer = WindowAnalyzer::new(window_size);
+    for reading in readings {
+        analyzer.push(reading);
+    }
+
+    analyzer.stats()
+}
+
+/// Why readings could not be streamed from a reader.
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading failed.
+    Io(io::Error),
+    /// A line is not a reading.
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "Could not read the readings: {}", e),
+            ReadError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+/// Like [`analyze`], but reads one reading per line from `reader` as it goes,
+/// such as a locked stdin.
+///
+/// ## Panics
+///
+/// If `window_size` is 0.
+pub fn analyze_reader(reader: impl BufRead, window_size: usize) -> Result<WindowStats, ReadError> {
+    let mut analyzer = WindowAnalyzer::new(window_size);
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(ReadError::Io)?;
+        analyzer.push(aoc_core::parse::number(index, &line, &line).map_err(ReadError::Parse)?);
+    }
+
+    Ok(analyzer.stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{
+        parse_readings,
+        window::{analyze, analyze_reader, WindowStats},
+        TEST_INPUT,
+    };
+
+    #[test]
+    fn single_readings() {
+        assert_eq!(
+            WindowStats {
+                increases: 7,
+                decreases: 2,
+                plateaus: 0,
+                longest_increasing_run: 3,
+            },
+            analyze(parse_readings(TEST_INPUT).unwrap(), 1)
+        );
+    }
+
+    #[test]
+    fn windows() {
+        assert_eq!(
+            WindowStats {
+                increases: 5,
+                decreases: 1,
+                plateaus: 1,
+                longest_increasing_run: 4,
+            },
+            analyze(parse_readings(TEST_INPUT).unwrap(), 3)
+        );
+
+        // A window larger than the sweep never fills up
+        assert_eq!(WindowStats::default(), analyze([1, 2, 3], 4));
+    }
+
+    #[test]
+    fn reader() {
+        assert_eq!(
+            analyze(parse_readings(TEST_INPUT).unwrap(), 3),
+            analyze_reader(Cursor::new(TEST_INPUT), 3).unwrap()
+        );
+        assert_eq!(
+            "line 3, column 1: Expected a number, found `20x`",
+            analyze_reader(Cursor::new("199\n200\n20x\n"), 2)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
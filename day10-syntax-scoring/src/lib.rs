@@ -0,0 +1,426 @@
+//! Day 10: Syntax Scoring.
+//!
+//! Parses lines of nested brackets into a tree of chunks, to find corrupted
+//! lines (with a mismatched closing bracket) and complete incomplete ones.
+
+#![warn(missing_docs)]
+
+use std::{
+    iter::{Enumerate, Peekable},
+    str::Chars,
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 10.
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Parsed<'a> = Vec<Vec<Chunk>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Chunk>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Vec<Vec<Chunk>>) -> Option<usize> {
+        Some(first_illegal_character_score_sum(lines))
+    }
+
+    fn part2(lines: &Vec<Vec<Chunk>>) -> Option<usize> {
+        middle_autocomplete_score(lines)
+    }
+}
+
+/// Parses every line into its chunks, see [`parse`].
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Chunk>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|e| e.on_line(index)))
+        .collect()
+}
+
+/// Sums the scores of the first illegal character of every corrupted line.
+pub fn first_illegal_character_score_sum(lines: &[Vec<Chunk>]) -> usize {
+    lines
+        .iter()
+        .filter_map(|chunk| first_illegal_character(chunk))
+        .map(illegal_character_score)
+        .sum()
+}
+
+/// Scores the autocompletion of every incomplete (but not corrupted) line, and
+/// returns the middle score, or `None` if there are no incomplete lines.
+pub fn middle_autocomplete_score(lines: &[Vec<Chunk>]) -> Option<usize> {
+    let mut scores: Vec<_> = lines
+        .iter()
+        .filter(|chunk| first_illegal_character(chunk).is_none())
+        .filter_map(|chunk| get_autocomplete(chunk))
+        .map(|autocomplete| get_autocomplete_score(&autocomplete))
+        .collect();
+
+    scores.sort_unstable();
+
+    scores.get(scores.len() / 2).copied()
+}
+
+/// The kind of brackets a chunk is wrapped in.
+#[derive(Debug, PartialEq)]
+pub enum Brace {
+    /// `()`
+    Parentheses,
+    /// `[]`
+    SquareBrackets,
+    /// `{}`
+    CurlyBraces,
+    /// `<>`
+    AngleBrackets,
+}
+
+/// How a chunk was closed.
+#[derive(Debug, PartialEq)]
+pub enum ClosingBrace {
+    /// With the closing bracket that matches the opening one.
+    Correct,
+    /// Not at all, because the line ended first.
+    Missing,
+    /// With a closing bracket of the wrong kind, which makes the line corrupted.
+    Invalid(char),
+}
+
+/// A chunk of brackets, e.g. `[<>()]`, with the chunks nested inside it.
+#[derive(Debug, PartialEq)]
+pub struct Chunk {
+    /// The kind of bracket that opened this chunk.
+    pub opening_brace: Brace,
+    /// The chunks nested inside this one, in order.
+    pub children: Vec<Chunk>,
+    /// How this chunk was closed.
+    pub closing_brace: ClosingBrace,
+}
+
+/// Finds the first closing bracket that does not match its opening bracket, if
+/// any, looking inside nested chunks first.
+pub fn first_illegal_character(chunk: &[Chunk]) -> Option<char> {
+    chunk.iter().find_map(|chunk| {
+        if let Some(illegal_child_chunk_closing_brace) = first_illegal_character(&chunk.children) {
+            return Some(illegal_child_chunk_closing_brace);
+        }
+
+        match chunk.closing_brace {
+            ClosingBrace::Invalid(c) => Some(c),
+            _ => None,
+        }
+    })
+}
+
+/// The syntax error score of an illegal closing bracket.
+pub fn illegal_character_score(c: char) -> usize {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+/// Parses a single line of brackets into its top level chunks. Parsing goes on
+/// after an invalid closing bracket, treating it as if it were the right one.
+/// Errors point at line 1.
+pub fn parse(input: &str) -> Result<Vec<Chunk>, ParseError> {
+    let mut chunks = vec![];
+    let mut chars = input.chars().enumerate().peekable();
+    while chars.peek().is_some() {
+        chunks.push(parse_internal(&mut chars)?);
+    }
+
+    Ok(chunks)
+}
+
+fn parse_internal(input: &mut Peekable<Enumerate<Chars>>) -> Result<Chunk, ParseError> {
+    let opening_brace = match input.peek() {
+        Some((_, '(')) => Brace::Parentheses,
+        Some((_, '[')) => Brace::SquareBrackets,
+        Some((_, '{')) => Brace::CurlyBraces,
+        Some((_, '<')) => Brace::AngleBrackets,
+        Some((column, c @ (')' | ']' | '}' | '>'))) => {
+            return Err(ParseError::new(
+                1,
+                column + 1,
+                format!("Closing bracket `{}` without an opening bracket", c),
+            ))
+        }
+        Some((column, c)) => {
+            return Err(ParseError::new(
+                1,
+                column + 1,
+                format!("Expected a bracket, found `{}`", c),
+            ))
+        }
+        None => unreachable!("Chunks are only parsed when there are characters left"),
+    };
+
+    // Consume opening brace
+    input.next();
+
+    let mut children = vec![];
+    let mut closing_brace = ClosingBrace::Missing;
+    while let Some((_, c)) = input.peek() {
+        match c {
+            ')' => {
+                closing_brace = if opening_brace == Brace::Parentheses {
+                    ClosingBrace::Correct
+                } else {
+                    ClosingBrace::Invalid(*c)
+                };
+                break;
+            }
+            ']' => {
+                closing_brace = if opening_brace == Brace::SquareBrackets {
+                    ClosingBrace::Correct
+                } else {
+                    ClosingBrace::Invalid(*c)
+                };
+                break;
+            }
+            '}' => {
+                closing_brace = if opening_brace == Brace::CurlyBraces {
+                    ClosingBrace::Correct
+                } else {
+                    ClosingBrace::Invalid(*c)
+                };
+                break;
+            }
+            '>' => {
+                closing_brace = if opening_brace == Brace::AngleBrackets {
+                    ClosingBrace::Correct
+                } else {
+                    ClosingBrace::Invalid(*c)
+                };
+                break;
+            }
+            _ => children.push(parse_internal(input)?),
+        }
+    }
+
+    // Consume the closing brace
+    input.next();
+
+    Ok(Chunk {
+        opening_brace,
+        children,
+        closing_brace,
+    })
+}
+
+/// The closing brackets that are needed to complete the last chunk, if it is
+/// incomplete.
+pub fn get_autocomplete(chunk: &[Chunk]) -> Option<String> {
+    // Note that only the last chunk could possibly have missing characters.
+    // Otherwise it could not be the last chunk.
+    match chunk.last() {
+        Some(chunk) => {
+            if chunk.closing_brace != ClosingBrace::Missing {
+                // Then none of the children's closing braces could be missing either
+                return None;
+            }
+
+            Some(format!(
+                "{}{}",
+                get_autocomplete(&chunk.children).unwrap_or_default(),
+                match chunk.closing_brace {
+                    ClosingBrace::Missing => match chunk.opening_brace {
+                        Brace::Parentheses => ")",
+                        Brace::SquareBrackets => "]",
+                        Brace::CurlyBraces => "}",
+                        Brace::AngleBrackets => ">",
+                    },
+                    _ => "",
+                }
+            ))
+        }
+        None => None,
+    }
+}
+
+/// Scores a completion string: for every character, multiply by 5 and add the
+/// value of that character.
+pub fn get_autocomplete_score(autocomplete: &str) -> usize {
+    autocomplete.chars().fold(0, |acc, c| {
+        acc * 5
+            + match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => 0,
+            }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![Chunk {
+                opening_brace: Brace::Parentheses,
+                children: vec![],
+                closing_brace: ClosingBrace::Correct
+            }],
+            parse("()").unwrap()
+        );
+        assert_eq!(
+            vec![Chunk {
+                opening_brace: Brace::SquareBrackets,
+                children: vec![Chunk {
+                    opening_brace: Brace::Parentheses,
+                    children: vec![Chunk {
+                        opening_brace: Brace::SquareBrackets,
+                        children: vec![],
+                        closing_brace: ClosingBrace::Correct
+                    }],
+                    closing_brace: ClosingBrace::Correct
+                },],
+                closing_brace: ClosingBrace::Invalid(')')
+            }],
+            parse("[([]))").unwrap()
+        );
+        assert_eq!(
+            vec![Chunk {
+                opening_brace: Brace::CurlyBraces,
+                children: vec![
+                    Chunk {
+                        opening_brace: Brace::SquareBrackets,
+                        children: vec![],
+                        closing_brace: ClosingBrace::Correct
+                    },
+                    Chunk {
+                        opening_brace: Brace::Parentheses,
+                        children: vec![Chunk {
+                            opening_brace: Brace::AngleBrackets,
+                            children: vec![],
+                            closing_brace: ClosingBrace::Missing
+                        },],
+                        closing_brace: ClosingBrace::Missing
+                    },
+                ],
+                closing_brace: ClosingBrace::Missing
+            }],
+            parse("{[](<").unwrap()
+        );
+        assert_eq!(
+            vec![
+                Chunk {
+                    opening_brace: Brace::Parentheses,
+                    children: vec![],
+                    closing_brace: ClosingBrace::Correct
+                },
+                Chunk {
+                    opening_brace: Brace::SquareBrackets,
+                    children: vec![Chunk {
+                        opening_brace: Brace::CurlyBraces,
+                        children: vec![],
+                        closing_brace: ClosingBrace::Missing
+                    },],
+                    closing_brace: ClosingBrace::Missing
+                },
+            ],
+            parse("()[{").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_first_illegal_character() {
+        // {([(<{}[<>[]}>{[]{[(<()> - Expected ], but found } instead.
+        assert_eq!(
+            Some('}'),
+            first_illegal_character(&parse("{([(<{}[<>[]}>{[]{[(<()>").unwrap())
+        );
+        // [[<[([]))<([[{}[[()]]] - Expected ], but found ) instead.
+        assert_eq!(
+            Some(')'),
+            first_illegal_character(&parse("[[<[([]))<([[{}[[()]]]").unwrap())
+        );
+        // [{[{({}]{}}([{[{{{}}([] - Expected ), but found ] instead.
+        assert_eq!(
+            Some(']'),
+            first_illegal_character(&parse("[{[{({}]{}}([{[{{{}}([]").unwrap())
+        );
+        // [<(<(<(<{}))><([]([]() - Expected >, but found ) instead.
+        assert_eq!(
+            Some(')'),
+            first_illegal_character(&parse("[<(<(<(<{}))><([]([]()").unwrap())
+        );
+        // <{([([[(<>()){}]>(<<{{ - Expected ], but found > instead.
+        assert_eq!(
+            Some('>'),
+            first_illegal_character(&parse("<{([([[(<>()){}]>(<<{{").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            26397,
+            first_illegal_character_score_sum(&parse_input(TEST_INPUT).unwrap())
+        )
+    }
+
+    #[test]
+    fn test_get_autocomplete() {
+        assert_eq!(
+            Some(")}>]})".to_owned()),
+            get_autocomplete(&parse("[(()[<>])]({[<{<<[]>>(").unwrap())
+        )
+    }
+
+    #[test]
+    fn test_get_autocomplete_score() {
+        assert_eq!(5566, get_autocomplete_score(")}>]})"))
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Some(288957),
+            middle_autocomplete_score(&parse_input(TEST_INPUT).unwrap())
+        );
+        assert_eq!(None, middle_autocomplete_score(&parse_input("").unwrap()));
+        assert_eq!(None, middle_autocomplete_score(&parse_input("(]").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 2, column 3: Closing bracket `)` without an opening bracket",
+            parse_input("()\n[])").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 2: Expected a bracket, found `x`",
+            parse("(x)").unwrap_err().to_string()
+        );
+    }
+}
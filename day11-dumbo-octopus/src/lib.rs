@@ -0,0 +1,145 @@
+//! Day 11: Dumbo Octopus.
+//!
+//! Simulates a grid of bioluminescent octopuses that flash when their energy
+//! level exceeds 9, bumping all of their neighbours.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Position};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 11.
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Option<usize> {
+        Some(run_simulation(&mut grid.clone(), Some(100)).0)
+    }
+
+    fn part2(grid: &Grid<u8>) -> Option<usize> {
+        run_simulation(&mut grid.clone(), None).1
+    }
+}
+
+/// Parses the energy level of each octopus, one digit per octopus. Every line
+/// has to be equally long.
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input)
+}
+
+/// Runs the simulation for the given number of `steps`, or until every
+/// octopus flashes at once if no number of steps is given.
+///
+/// ## Returns
+///
+/// The total number of flashes, and, if `steps` was `None`, the first step
+/// during which all octopuses flashed.
+pub fn run_simulation(grid: &mut Grid<u8>, steps: Option<usize>) -> (usize, Option<usize>) {
+    let mut flashes = 0;
+    let mut flashed = Vec::new();
+    let mut first_all_flash = None;
+    let grid_length = grid.cells().len();
+
+    for step in match steps {
+        Some(steps) => 1..=steps,
+        None => 1..=usize::MAX,
+    } {
+        for index in 0..grid_length {
+            let (flashed_count, newly_flashed) = bump(grid, index);
+            flashes += flashed_count;
+
+            if let Some(mut newly_flashed) = newly_flashed {
+                flashed.append(&mut newly_flashed);
+            }
+        }
+
+        if steps.is_none() && flashed.len() == grid_length {
+            first_all_flash = Some(step);
+            break;
+        }
+
+        for position in flashed.iter() {
+            grid[*position] = 0
+        }
+
+        flashed.clear();
+    }
+
+    (flashes, first_all_flash)
+}
+
+/// Increases the energy level of the octopus at `index`, cascading to its
+/// neighbours if it flashes.
+///
+/// ## Returns
+///
+/// The number of flashes this caused, and the octopuses that flashed.
+fn bump(grid: &mut Grid<u8>, index: usize) -> (usize, Option<Vec<Position>>) {
+    let fields = grid.cells_mut();
+
+    // If it's already 10 (= flashed), nothing to do
+    if fields[index] == 10 {
+        return (0, None);
+    }
+
+    fields[index] += 1;
+    if fields[index] < 10 {
+        return (0, None);
+    }
+
+    let position = grid.coordinates_from_index(index);
+    let mut flashes = 1;
+    let mut flashed = vec![position];
+    for neighbour in grid.neighbours8(position).collect::<Vec<_>>() {
+        let (flashed_count, newly_flashed) = bump(grid, grid.index_from_coordinates(neighbour));
+        flashes += flashed_count;
+
+        if let Some(mut newly_flashed) = newly_flashed {
+            flashed.append(&mut newly_flashed);
+        }
+    }
+
+    (flashes, Some(flashed))
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+#[test]
+fn test_part1() {
+    let mut parsed = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(204, run_simulation(&mut parsed.clone(), Some(10)).0);
+    assert_eq!(1656, run_simulation(&mut parsed, Some(100)).0);
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        Some(195),
+        run_simulation(&mut parse_input(TEST_INPUT).unwrap(), None).1
+    );
+}
@@ -0,0 +1,357 @@
+//! Day 12: Passage Pathing.
+//!
+//! Finds every path from `start` to `end` through a system of caves, where
+//! small caves can only be visited a limited number of times.
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 12.
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Parsed<'a> = CaveSystem;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<CaveSystem, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(caves: &CaveSystem) -> Option<u64> {
+        Some(caves.count_paths(Rule::SmallCavesOnce))
+    }
+
+    fn part2(caves: &CaveSystem) -> Option<u64> {
+        Some(caves.count_paths(Rule::OneSmallCaveTwice))
+    }
+}
+
+/// How often small caves may be visited on a single path. `start` is never
+/// visited twice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Every small cave at most once.
+    SmallCavesOnce,
+    /// A single small cave twice, and all the other ones at most once.
+    OneSmallCaveTwice,
+}
+
+/// A single cave, and the caves it is connected to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cave {
+    /// The name of the cave, e.g. `start`, `A` or `dc`.
+    pub name: String,
+    /// Whether the name is lowercase, which limits how often it can be visited.
+    pub is_small: bool,
+    /// Indices of the connected caves in [`CaveSystem::caves`].
+    pub neighbours: Vec<usize>,
+}
+
+/// The graph of caves and the passages between them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CaveSystem {
+    caves: Vec<Cave>,
+    start: usize,
+    end: usize,
+}
+
+/// Where counting paths left off: the current cave, the small caves visited
+/// so far (as a bit per cave), and whether a small cave may still be visited
+/// twice.
+type PathState = (usize, u64, bool);
+
+impl CaveSystem {
+    /// All caves, in the order they first appear in the input.
+    pub fn caves(&self) -> &[Cave] {
+        &self.caves
+    }
+
+    /// Finds a cave by its name.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.caves.iter().position(|cave| cave.name == name)
+    }
+
+    /// Counts the paths from `start` to `end` without listing them, so that it
+    /// stays fast for large cave systems.
+    pub fn count_paths(&self, rule: Rule) -> u64 {
+        let mut memoized = HashMap::new();
+
+        self.count_from(
+            (self.start, 1 << self.start, rule == Rule::OneSmallCaveTwice),
+            &mut memoized,
+        )
+    }
+
+    fn count_from(&self, state: PathState, memoized: &mut HashMap<PathState, u64>) -> u64 {
+        let (cave, visited, can_revisit) = state;
+        if cave == self.end {
+            return 1;
+        }
+        if let Some(previously_calculated) = memoized.get(&state) {
+            return *previously_calculated;
+        }
+
+        let paths = self
+            .next_caves(cave, visited, can_revisit)
+            .map(|next| self.count_from(next, memoized))
+            .sum();
+        memoized.insert(state, paths);
+
+        paths
+    }
+
+    /// Lists every path from `start` to `end`, e.g. `["start", "A", "end"]`.
+    /// The number of paths grows quickly, see [`CaveSystem::count_paths`] to
+    /// only count them.
+    pub fn paths(&self, rule: Rule) -> Vec<Vec<&str>> {
+        let mut paths = vec![];
+        let mut path = vec![self.start];
+        self.paths_from(
+            (self.start, 1 << self.start, rule == Rule::OneSmallCaveTwice),
+            &mut path,
+            &mut paths,
+        );
+
+        paths
+    }
+
+    fn paths_from<'a>(
+        &'a self,
+        state: PathState,
+        path: &mut Vec<usize>,
+        paths: &mut Vec<Vec<&'a str>>,
+    ) {
+        let (cave, visited, can_revisit) = state;
+        if cave == self.end {
+            paths.push(
+                path.iter()
+                    .map(|&cave| self.caves[cave].name.as_str())
+                    .collect(),
+            );
+            return;
+        }
+
+        for next in self.next_caves(cave, visited, can_revisit) {
+            path.push(next.0);
+            self.paths_from(next, path, paths);
+            path.pop();
+        }
+    }
+
+    /// The states reachable from `cave` in a single step.
+    fn next_caves(
+        &self,
+        cave: usize,
+        visited: u64,
+        can_revisit: bool,
+    ) -> impl Iterator<Item = PathState> + '_ {
+        self.caves[cave].neighbours.iter().filter_map(move |&next| {
+            let bit = 1 << next;
+            if next == self.start {
+                None
+            } else if !self.caves[next].is_small {
+                Some((next, visited, can_revisit))
+            } else if visited & bit == 0 {
+                Some((next, visited | bit, can_revisit))
+            } else if can_revisit && next != self.end {
+                Some((next, visited, false))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Parses the passages between caves, one per line, e.g. `start-A`.
+///
+/// Two big caves can't be connected to each other, as that would allow
+/// infinitely many paths. At most 64 caves are supported.
+pub fn parse_input(input: &str) -> Result<CaveSystem, ParseError> {
+    let mut caves: Vec<Cave> = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let (from, to) = line.split_once('-').ok_or_else(|| {
+            ParseError::at(index, line, line, "Expected a passage, such as `start-A`")
+        })?;
+
+        let mut find_or_add = |name: &str| {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    name,
+                    format!("Expected the name of a cave, found `{}`", name),
+                ));
+            }
+
+            if let Some(cave) = caves.iter().position(|cave| cave.name == name) {
+                return Ok(cave);
+            }
+            if caves.len() == 64 {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    name,
+                    "There are more than 64 caves",
+                ));
+            }
+
+            caves.push(Cave {
+                name: name.to_owned(),
+                is_small: name.chars().all(|c| c.is_ascii_lowercase()),
+                neighbours: vec![],
+            });
+            Ok(caves.len() - 1)
+        };
+        let (from, to) = (find_or_add(from)?, find_or_add(to)?);
+
+        if !caves[from].is_small && !caves[to].is_small {
+            return Err(ParseError::at(
+                index,
+                line,
+                line,
+                "Two big caves can't be connected, there would be infinitely many paths",
+            ));
+        }
+
+        caves[from].neighbours.push(to);
+        caves[to].neighbours.push(from);
+    }
+
+    let find = |name: &str| {
+        caves
+            .iter()
+            .position(|cave| cave.name == name)
+            .ok_or_else(|| ParseError::end_of_input(input, format!("There is no `{}` cave", name)))
+    };
+
+    Ok(CaveSystem {
+        start: find("start")?,
+        end: find("end")?,
+        caves,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, Rule};
+
+    const SMALL_EXAMPLE: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const MEDIUM_EXAMPLE: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    const LARGE_EXAMPLE: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    #[test]
+    fn parse() {
+        let caves = parse_input(SMALL_EXAMPLE).unwrap();
+        assert_eq!(6, caves.caves().len());
+
+        let a = &caves.caves()[caves.find("A").unwrap()];
+        assert!(!a.is_small);
+        assert_eq!(4, a.neighbours.len());
+
+        assert_eq!(
+            "line 2, column 1: Expected a passage, such as `start-A`",
+            parse_input("start-A\nA end").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 7: Expected the name of a cave, found `A1`",
+            parse_input("start-A1").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 1: Two big caves can't be connected, there would be infinitely many paths",
+            parse_input("start-A\nA-B").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 8: There is no `end` cave",
+            parse_input("start-A").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let caves = parse_input(SMALL_EXAMPLE).unwrap();
+        let paths = caves.paths(Rule::SmallCavesOnce);
+        assert_eq!(10, paths.len());
+        assert!(paths.contains(&vec!["start", "A", "b", "A", "c", "A", "end"]));
+        assert!(paths.contains(&vec!["start", "b", "end"]));
+
+        assert_eq!(10, caves.count_paths(Rule::SmallCavesOnce));
+        assert_eq!(
+            19,
+            parse_input(MEDIUM_EXAMPLE)
+                .unwrap()
+                .count_paths(Rule::SmallCavesOnce)
+        );
+        assert_eq!(
+            226,
+            parse_input(LARGE_EXAMPLE)
+                .unwrap()
+                .count_paths(Rule::SmallCavesOnce)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        let caves = parse_input(SMALL_EXAMPLE).unwrap();
+        let paths = caves.paths(Rule::OneSmallCaveTwice);
+        assert_eq!(36, paths.len());
+        assert!(paths.contains(&vec!["start", "A", "b", "A", "b", "A", "c", "A", "end"]));
+        assert!(!paths.contains(&vec!["start", "A", "b", "A", "b", "A", "b", "end"]));
+
+        assert_eq!(36, caves.count_paths(Rule::OneSmallCaveTwice));
+        assert_eq!(
+            103,
+            parse_input(MEDIUM_EXAMPLE)
+                .unwrap()
+                .count_paths(Rule::OneSmallCaveTwice)
+        );
+
+        let caves = parse_input(LARGE_EXAMPLE).unwrap();
+        assert_eq!(3509, caves.count_paths(Rule::OneSmallCaveTwice));
+        assert_eq!(3509, caves.paths(Rule::OneSmallCaveTwice).len());
+    }
+}
@@ -0,0 +1,5 @@
+use day12_passage_pathing::{PassagePathing, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<PassagePathing>(BUNDLED_INPUT);
+}
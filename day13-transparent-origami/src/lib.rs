@@ -0,0 +1,273 @@
+//! Day 13: Transparent Origami.
+//!
+//! Folds a transparent sheet of paper covered in dots, to reveal the code that
+//! activates the thermal imaging camera.
+
+#![warn(missing_docs)]
+
+use std::collections::HashSet;
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 13. The second answer is the folded paper, rendered
+/// with [`as_text`].
+pub struct TransparentOrigami;
+
+impl Solution for TransparentOrigami {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    type Parsed<'a> = (Dots, Vec<FoldInstruction>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((set, instructions): &Self::Parsed<'_>) -> Option<usize> {
+        instructions
+            .first()
+            .map(|instruction| execute_fold(set, instruction).len())
+    }
+
+    fn part2((set, instructions): &Self::Parsed<'_>) -> Option<String> {
+        let set = instructions.iter().fold(set.clone(), |set, instruction| {
+            execute_fold(&set, instruction)
+        });
+
+        Some(as_text(&set))
+    }
+}
+
+/// The positions of the dots on the paper, as `(x, y)`.
+pub type Dots = HashSet<(usize, usize)>;
+
+/// Where to fold the paper.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FoldInstruction {
+    /// Fold the right half to the left, along the vertical line `x = n`.
+    X(usize),
+    /// Fold the bottom half up, along the horizontal line `y = n`.
+    Y(usize),
+}
+
+/// Parses the dots as `x,y` coordinates, followed by a blank line and the fold
+/// instructions, such as `fold along y=7`. Folds that would move dots past the
+/// left or top edge of the paper are rejected.
+pub fn parse(input: &str) -> Result<(Dots, Vec<FoldInstruction>), ParseError> {
+    let mut set = Dots::new();
+    let mut lines = input.lines().enumerate();
+
+    for (index, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(index, line, line, "Expected a dot, such as `6,10`"))?;
+        set.insert((
+            aoc_core::parse::number(index, line, x)?,
+            aoc_core::parse::number(index, line, y)?,
+        ));
+    }
+
+    // The rest are fold instructions. Fold a copy of the dots along the way, to
+    // make sure no fold moves them past 0.
+    let mut instructions = vec![];
+    let mut folded = set.clone();
+    for (index, line) in lines {
+        let (axis, at) = line
+            .strip_prefix("fold along ")
+            .and_then(|line| line.split_once('='))
+            .ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Expected a fold instruction, such as `fold along y=7`",
+                )
+            })?;
+        let position = aoc_core::parse::number(index, line, at)?;
+
+        let (instruction, max) = match axis {
+            "x" => (
+                FoldInstruction::X(position),
+                folded.iter().map(|&(x, _)| x).max(),
+            ),
+            "y" => (
+                FoldInstruction::Y(position),
+                folded.iter().map(|&(_, y)| y).max(),
+            ),
+            _ => {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    axis,
+                    format!("Expected the axis to be x or y, found `{}`", axis),
+                ))
+            }
+        };
+        if let Some(max) = max.filter(|&max| max > 2 * position) {
+            return Err(ParseError::at(
+                index,
+                line,
+                at,
+                format!(
+                    "Folding along {}={} would move the dots at {}={} past 0",
+                    axis, position, axis, max
+                ),
+            ));
+        }
+
+        folded = execute_fold(&folded, &instruction);
+        instructions.push(instruction);
+    }
+
+    Ok((set, instructions))
+}
+
+/// Folds the paper once, returning where the dots end up. Overlapping dots
+/// merge into one.
+pub fn execute_fold(
+    input: &HashSet<(usize, usize)>,
+    instruction: &FoldInstruction,
+) -> HashSet<(usize, usize)> {
+    input
+        .iter()
+        .map(|&(x, y)| {
+            (
+                match *instruction {
+                    FoldInstruction::X(at) if x < at => x,
+                    FoldInstruction::X(at) => at - (x - at),
+                    _ => x,
+                },
+                match *instruction {
+                    FoldInstruction::Y(at) if y < at => y,
+                    FoldInstruction::Y(at) => at - (y - at),
+                    _ => y,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Renders the dots as `#`, and empty spots as `.`, one line per row. Without
+/// any dots, the text is empty.
+pub fn as_text(set: &HashSet<(usize, usize)>) -> String {
+    if set.is_empty() {
+        return String::new();
+    }
+
+    let (width, height) = set.iter().fold((0, 0), |(width, height), &(x, y)| {
+        (width.max(x + 1), height.max(y + 1))
+    });
+
+    let mut result = vec!['.'; width * height];
+    for &(x, y) in set {
+        result[y * width + x] = '#';
+    }
+
+    result
+        .chunks(width)
+        .map(|line| String::from_iter(line.iter()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{execute_fold, FoldInstruction};
+
+    const TEST_INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn parse() {
+        let (set, instructions) = crate::parse(TEST_INPUT).unwrap();
+        assert_eq!(18, set.len());
+        assert!(set.contains(&(6, 12)));
+        assert_eq!(2, instructions.len());
+        assert_eq!(FoldInstruction::Y(7), instructions[0]);
+    }
+
+    #[test]
+    fn part1() {
+        let (set, instructions) = crate::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(17, execute_fold(&set, &instructions[0]).len());
+    }
+
+    #[test]
+    fn as_text() {
+        let (mut set, instructions) = crate::parse(TEST_INPUT).unwrap();
+        for instruction in instructions {
+            set = execute_fold(&set, &instruction);
+        }
+
+        assert_eq!(
+            "#####
+#...#
+#...#
+#...#
+#####",
+            crate::as_text(&set)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "line 2, column 3: Expected a number, found `1O`",
+            crate::parse("6,10\n0,1O").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 3, column 12: Expected the axis to be x or y, found `z`",
+            crate::parse("6,10\n\nfold along z=7")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 3, column 14: Folding along x=2 would move the dots at x=10 past 0",
+            crate::parse("10,0\n\nfold along x=2")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 4, column 14: Folding along y=1 would move the dots at y=4 past 0",
+            crate::parse("0,4\n\nfold along y=5\nfold along y=1")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let (set, instructions) = crate::parse("").unwrap();
+        assert!(instructions.is_empty());
+        assert_eq!("", crate::as_text(&set));
+    }
+}
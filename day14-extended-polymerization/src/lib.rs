@@ -0,0 +1,272 @@
+//! Day 14: Extended Polymerization.
+//!
+//! Grows a polymer by repeatedly inserting an element between every pair of
+//! adjacent elements, following a set of pair insertion rules.
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 14.
+pub struct ExtendedPolymerization;
+
+impl Solution for ExtendedPolymerization {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    type Parsed<'a> = Polymer;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Polymer, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(polymer: &Polymer) -> Option<usize> {
+        spread(&simulate_optimised(polymer, 10))
+    }
+
+    fn part2(polymer: &Polymer) -> Option<usize> {
+        spread(&simulate_optimised(polymer, 40))
+    }
+}
+
+/// The polymer template to start with, and the rules to grow it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polymer {
+    /// The elements of the starting polymer, e.g. `NNCB`.
+    pub template: Vec<u8>,
+    /// The element to insert between each pair of elements. Pairs without a
+    /// rule stay as they are.
+    pub rules: HashMap<[u8; 2], u8>,
+}
+
+/// How often each element occurs in a polymer.
+pub type ElementCounts = HashMap<u8, usize>;
+
+/// Parses the polymer template, followed by a blank line and the pair
+/// insertion rules, such as `CH -> B`. Elements are uppercase letters.
+pub fn parse_input(input: &str) -> Result<Polymer, ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    let template = match lines.next() {
+        Some((index, line)) => {
+            if let Some(offset) = line.find(|c: char| !c.is_ascii_uppercase()) {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    &line[offset..],
+                    "Elements are uppercase letters",
+                ));
+            }
+
+            line.as_bytes().to_vec()
+        }
+        None => return Err(ParseError::new(1, 1, "Expected a polymer template")),
+    };
+    if template.is_empty() {
+        return Err(ParseError::new(1, 1, "Expected a polymer template"));
+    }
+
+    match lines.next() {
+        Some((_, "")) => {}
+        Some((index, line)) => {
+            return Err(ParseError::at(
+                index,
+                line,
+                line,
+                "Expected a blank line after the template",
+            ))
+        }
+        None => {
+            return Err(ParseError::end_of_input(
+                input,
+                "Expected the pair insertion rules",
+            ))
+        }
+    }
+
+    let mut rules = HashMap::new();
+    for (index, line) in lines {
+        match line.as_bytes() {
+            [a, b, b' ', b'-', b'>', b' ', c]
+                if [a, b, c].iter().all(|element| element.is_ascii_uppercase()) =>
+            {
+                rules.insert([*a, *b], *c);
+            }
+            _ => {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Expected a pair insertion rule, such as `CH -> B`",
+                ))
+            }
+        }
+    }
+
+    Ok(Polymer { template, rules })
+}
+
+/// Grows the whole polymer for the given number of `steps`, and returns it.
+/// Its length roughly doubles every step, so this is only feasible for a small
+/// number of steps.
+pub fn simulate_naive(polymer: &Polymer, steps: usize) -> Vec<u8> {
+    let mut elements = polymer.template.clone();
+
+    for _ in 0..steps {
+        let mut grown = Vec::with_capacity(elements.len() * 2);
+        for pair in elements.windows(2) {
+            grown.push(pair[0]);
+            if let Some(&inserted) = polymer.rules.get(&[pair[0], pair[1]]) {
+                grown.push(inserted);
+            }
+        }
+        grown.extend(elements.last());
+
+        elements = grown;
+    }
+
+    elements
+}
+
+/// Counts how often each element occurs in `elements`.
+pub fn count_elements(elements: &[u8]) -> ElementCounts {
+    let mut counts = HashMap::new();
+    for element in elements {
+        *counts.entry(*element).or_default() += 1;
+    }
+
+    counts
+}
+
+/// Grows the polymer by only counting how often each pair of adjacent elements
+/// occurs, and returns how often each element occurs after `steps`.
+pub fn simulate_optimised(polymer: &Polymer, steps: usize) -> ElementCounts {
+    let mut pairs: HashMap<[u8; 2], usize> = HashMap::new();
+    for pair in polymer.template.windows(2) {
+        *pairs.entry([pair[0], pair[1]]).or_default() += 1;
+    }
+
+    for _ in 0..steps {
+        let mut grown = HashMap::with_capacity(pairs.len());
+        for (pair, count) in pairs {
+            // Inserting an element splits the pair into two new ones
+            match polymer.rules.get(&pair) {
+                Some(&inserted) => {
+                    *grown.entry([pair[0], inserted]).or_default() += count;
+                    *grown.entry([inserted, pair[1]]).or_default() += count;
+                }
+                None => *grown.entry(pair).or_default() += count,
+            }
+        }
+
+        pairs = grown;
+    }
+
+    // Every element is the first of a pair, except for the last one, which
+    // never changes.
+    let mut counts = HashMap::new();
+    for ([first, _], count) in pairs {
+        *counts.entry(first).or_default() += count;
+    }
+    if let Some(&last) = polymer.template.last() {
+        *counts.entry(last).or_default() += 1;
+    }
+
+    counts
+}
+
+/// The difference between the most and the least common element, or `None` if
+/// there are no elements.
+pub fn spread(counts: &ElementCounts) -> Option<usize> {
+    Some(counts.values().max()? - counts.values().min()?)
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+#[test]
+fn test_simulate_naive() {
+    let polymer = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(b"NCNBCHB".to_vec(), simulate_naive(&polymer, 1));
+    assert_eq!(
+        b"NBBBCNCCNBBNBNBBCHBHHBCHB".to_vec(),
+        simulate_naive(&polymer, 3)
+    );
+    assert_eq!(3073, simulate_naive(&polymer, 10).len());
+}
+
+#[test]
+fn test_part1() {
+    let polymer = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        Some(1588),
+        spread(&count_elements(&simulate_naive(&polymer, 10)))
+    );
+    assert_eq!(Some(1588), spread(&simulate_optimised(&polymer, 10)));
+}
+
+#[test]
+fn test_part2() {
+    let polymer = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        Some(2188189693529),
+        spread(&simulate_optimised(&polymer, 40))
+    );
+}
+
+#[test]
+fn test_simulations_agree() {
+    let polymer = parse_input(TEST_INPUT).unwrap();
+
+    for steps in 0..=12 {
+        assert_eq!(
+            count_elements(&simulate_naive(&polymer, steps)),
+            simulate_optimised(&polymer, steps)
+        );
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 1, column 3: Elements are uppercase letters",
+        parse_input("NNcB\n\nCH -> B").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 4, column 1: Expected a pair insertion rule, such as `CH -> B`",
+        parse_input("NNCB\n\nCH -> B\nCH => B")
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 1, column 5: Expected the pair insertion rules",
+        parse_input("NNCB").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,5 @@
+use day14_extended_polymerization::{ExtendedPolymerization, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<ExtendedPolymerization>(BUNDLED_INPUT);
+}
@@ -0,0 +1,249 @@
+//! Day 15: Chiton.
+//!
+//! Finds the path through a cave full of chitons that has the lowest total
+//! risk, on the map as given and on a map tiled five times in each direction.
+
+#![warn(missing_docs)]
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Position};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 15.
+pub struct Chiton;
+
+impl Solution for Chiton {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Option<u32> {
+        lowest_risk_path(grid, Search::AStar).map(|route| route.risk)
+    }
+
+    fn part2(grid: &Grid<u8>) -> Option<u32> {
+        lowest_risk_path(&expand(grid, 5), Search::AStar).map(|route| route.risk)
+    }
+}
+
+/// Parses the risk level of each position, one digit from 1 to 9 per position.
+/// Every line has to be equally long.
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .filter(|&digit| digit >= 1)
+            .map(|digit| digit as u8)
+    })
+}
+
+/// Tiles the map `times` times to the right and downwards. Every tile's risk
+/// levels are 1 higher than those of the tile to its left or above it, wrapping
+/// around from 9 back to 1.
+pub fn expand(grid: &Grid<u8>, times: usize) -> Grid<u8> {
+    let mut expanded = Grid::filled(grid.width() * times, grid.height() * times, 0);
+
+    for ((x, y), &risk) in grid.iter_with_positions() {
+        for tile_y in 0..times {
+            for tile_x in 0..times {
+                let increase = (tile_x + tile_y) as u8;
+                expanded[(tile_x * grid.width() + x, tile_y * grid.height() + y)] =
+                    (risk - 1 + increase) % 9 + 1;
+            }
+        }
+    }
+
+    expanded
+}
+
+/// How [`lowest_risk_path`] decides which position to look at next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Search {
+    /// The position with the lowest risk so far.
+    Dijkstra,
+    /// The position with the lowest risk so far plus the Manhattan distance to
+    /// the bottom right, which is the least risk left to take, as every
+    /// position has a risk of at least 1. Finds the same path while looking at
+    /// fewer positions.
+    AStar,
+}
+
+/// A path through the cave.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// The sum of the risk levels of every position entered. The starting
+    /// position is never entered, so it does not count.
+    pub risk: u32,
+    /// Every position along the way, from the top left to the bottom right.
+    pub path: Vec<Position>,
+}
+
+/// Finds the path from the top left to the bottom right of the map with the
+/// lowest total risk, moving horizontally or vertically.
+///
+/// ## Returns
+///
+/// The path and its risk, or `None` if the map is empty.
+pub fn lowest_risk_path(grid: &Grid<u8>, search: Search) -> Option<Route> {
+    if grid.cells().is_empty() {
+        return None;
+    }
+
+    let start = (0, 0);
+    let end = (grid.width() - 1, grid.height() - 1);
+    let estimate = |(x, y): Position| match search {
+        Search::Dijkstra => 0,
+        Search::AStar => ((end.0 - x) + (end.1 - y)) as u32,
+    };
+
+    let mut lowest_risk = Grid::filled(grid.width(), grid.height(), u32::MAX);
+    let mut came_from = Grid::filled(grid.width(), grid.height(), None);
+    let mut queue = BinaryHeap::new();
+    lowest_risk[start] = 0;
+    queue.push(Reverse((estimate(start), 0, start)));
+
+    while let Some(Reverse((_, risk, position))) = queue.pop() {
+        if position == end {
+            break;
+        }
+        // Already reached with less risk through another path
+        if risk > lowest_risk[position] {
+            continue;
+        }
+
+        for neighbour in grid.neighbours4(position) {
+            let neighbour_risk = risk + grid[neighbour] as u32;
+            if neighbour_risk < lowest_risk[neighbour] {
+                lowest_risk[neighbour] = neighbour_risk;
+                came_from[neighbour] = Some(position);
+                queue.push(Reverse((
+                    neighbour_risk + estimate(neighbour),
+                    neighbour_risk,
+                    neighbour,
+                )));
+            }
+        }
+    }
+
+    let mut path = vec![end];
+    while let Some(previous) = came_from[*path.last()?] {
+        path.push(previous);
+    }
+    path.reverse();
+
+    Some(Route {
+        risk: lowest_risk[end],
+        path,
+    })
+}
+
+/// Renders the map with only the risk levels along the `path`, and `.`
+/// everywhere else.
+pub fn render_path(grid: &Grid<u8>, path: &[Position]) -> String {
+    let mut rendered = grid.map(|_| '.');
+    for &position in path {
+        rendered[position] = char::from(b'0' + grid[position]);
+    }
+
+    rendered.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{expand, lowest_risk_path, parse_input, render_path, Route, Search};
+
+    const TEST_INPUT: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    /// Checks that the path starts at the top left, ends at the bottom right,
+    /// only takes single steps, and adds up to its risk.
+    fn assert_valid(grid: &aoc_grid::Grid<u8>, route: &Route) {
+        assert_eq!(Some(&(0, 0)), route.path.first());
+        assert_eq!(
+            Some(&(grid.width() - 1, grid.height() - 1)),
+            route.path.last()
+        );
+        for step in route.path.windows(2) {
+            assert!(grid
+                .neighbours4(step[0])
+                .any(|neighbour| neighbour == step[1]));
+        }
+        assert_eq!(
+            route.risk,
+            route.path[1..]
+                .iter()
+                .map(|&position| grid[position] as u32)
+                .sum()
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "line 2, column 3: Unexpected character `x`",
+            parse_input("123\n12x").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 2: Unexpected character `0`",
+            parse_input("10\n01").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+
+        for search in [Search::Dijkstra, Search::AStar] {
+            let route = lowest_risk_path(&grid, search).unwrap();
+            assert_eq!(40, route.risk);
+            assert_valid(&grid, &route);
+        }
+    }
+
+    #[test]
+    fn part2() {
+        let grid = expand(&parse_input(TEST_INPUT).unwrap(), 5);
+        assert_eq!(50, grid.width());
+        assert_eq!(
+            "11637517422274862853338597396444961841755517295286",
+            grid.row(0)
+                .iter()
+                .map(|risk| risk.to_string())
+                .collect::<String>()
+        );
+        assert_eq!(9, grid[(49, 49)]);
+
+        for search in [Search::Dijkstra, Search::AStar] {
+            let route = lowest_risk_path(&grid, search).unwrap();
+            assert_eq!(315, route.risk);
+            assert_valid(&grid, &route);
+        }
+    }
+
+    #[test]
+    fn render() {
+        let grid = parse_input("191\n111\n991").unwrap();
+        let route = lowest_risk_path(&grid, Search::AStar).unwrap();
+
+        assert_eq!(4, route.risk);
+        assert_eq!("1..\n111\n..1", render_path(&grid, &route.path));
+    }
+}
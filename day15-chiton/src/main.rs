@@ -0,0 +1,5 @@
+use day15_chiton::{Chiton, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<Chiton>(BUNDLED_INPUT);
+}
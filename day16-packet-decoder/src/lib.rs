@@ -0,0 +1,451 @@
+//! Day 16: Packet Decoder.
+//!
+//! Decodes the hierarchy of packets in a BITS transmission, and evaluates the
+//! expression they describe.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 16.
+pub struct PacketDecoder;
+
+impl Solution for PacketDecoder {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    type Parsed<'a> = Packet;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Packet, ParseError> {
+        parse(input)
+    }
+
+    fn part1(packet: &Packet) -> Option<usize> {
+        Some(version_sum(packet))
+    }
+
+    fn part2(packet: &Packet) -> Option<usize> {
+        Some(evaluate(packet))
+    }
+}
+
+/// A single packet, which is either a literal value or an operator on its
+/// sub-packets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Packet {
+    /// Version number from the packet header.
+    pub version: usize,
+    /// Type ID from the packet header. `4` is a literal value, anything else an
+    /// operator.
+    pub packet_type_id: usize,
+    /// The literal value or sub-packets.
+    pub contents: PacketContents,
+}
+
+/// What a packet carries.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketContents {
+    /// A single number.
+    Literal(usize),
+    /// The sub-packets the operator applies to.
+    Operator(Vec<Packet>),
+}
+
+/// Parses the outermost packet of a hexadecimal transmission. Trailing padding
+/// bits are ignored.
+pub fn parse(input: &str) -> Result<Packet, ParseError> {
+    parse_binary_packet(&parse_hexadecimal(input)?).map(|(packet, _)| packet)
+}
+
+/// Converts a hexadecimal transmission to its bits, most significant first.
+/// Trailing whitespace, such as a newline, is ignored.
+pub fn parse_hexadecimal(input: &str) -> Result<Vec<bool>, ParseError> {
+    let mut bits = Vec::with_capacity(input.len() * 4);
+    for (index, c) in input.trim_end().chars().enumerate() {
+        let digit = match c {
+            '0' => [0, 0, 0, 0],
+            '1' => [0, 0, 0, 1],
+            '2' => [0, 0, 1, 0],
+            '3' => [0, 0, 1, 1],
+            '4' => [0, 1, 0, 0],
+            '5' => [0, 1, 0, 1],
+            '6' => [0, 1, 1, 0],
+            '7' => [0, 1, 1, 1],
+            '8' => [1, 0, 0, 0],
+            '9' => [1, 0, 0, 1],
+            'A' => [1, 0, 1, 0],
+            'B' => [1, 0, 1, 1],
+            'C' => [1, 1, 0, 0],
+            'D' => [1, 1, 0, 1],
+            'E' => [1, 1, 1, 0],
+            'F' => [1, 1, 1, 1],
+            _ => {
+                return Err(ParseError::new(
+                    1,
+                    index + 1,
+                    format!("Expected a hexadecimal digit, found `{}`", c),
+                ))
+            }
+        };
+
+        bits.extend(digit.iter().map(|i| *i == 1));
+    }
+
+    Ok(bits)
+}
+
+/// Parses a single packet, including its sub-packets, from the start of `input`.
+///
+/// ## Returns
+///
+/// The packet, and the bits that follow it.
+pub fn parse_binary_packet(input: &[bool]) -> Result<(Packet, &[bool]), ParseError> {
+    let mut reader = Reader {
+        bits: input,
+        position: 0,
+        end: input.len(),
+    };
+    let packet = reader.packet()?;
+
+    Ok((packet, &input[reader.position..]))
+}
+
+/// Reads a transmission bit by bit, keeping track of the position to point
+/// errors at the right hexadecimal digit.
+struct Reader<'a> {
+    bits: &'a [bool],
+    position: usize,
+    /// Where reading has to stop, which is before the end of the transmission
+    /// while reading the sub-packets of an operator with a fixed length.
+    end: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [bool], ParseError> {
+        if self.position + count > self.end {
+            let reason = if self.end < self.bits.len() {
+                "Sub-packets are longer than their operator packet allows"
+            } else {
+                "Transmission ends in the middle of a packet"
+            };
+
+            return Err(ParseError::new(1, self.end / 4 + 1, reason));
+        }
+
+        let bits = &self.bits[self.position..self.position + count];
+        self.position += count;
+
+        Ok(bits)
+    }
+
+    fn number(&mut self, count: usize) -> Result<usize, ParseError> {
+        self.take(count).map(from_bits)
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        let start = self.position;
+        let version = self.number(3)?;
+        let packet_type_id = self.number(3)?;
+
+        let contents = if packet_type_id == 4 {
+            self.literal()?
+        } else {
+            self.operator(start, packet_type_id)?
+        };
+
+        Ok(Packet {
+            version,
+            packet_type_id,
+            contents,
+        })
+    }
+
+    fn literal(&mut self) -> Result<PacketContents, ParseError> {
+        let mut bits = vec![];
+        loop {
+            let more = self.take(1)?[0];
+            bits.extend_from_slice(self.take(4)?);
+
+            if !more {
+                break;
+            }
+        }
+
+        Ok(PacketContents::Literal(from_bits(&bits)))
+    }
+
+    /// Reads the sub-packets of the operator packet that starts at `start`.
+    fn operator(
+        &mut self,
+        start: usize,
+        packet_type_id: usize,
+    ) -> Result<PacketContents, ParseError> {
+        let mut result = vec![];
+        if self.take(1)?[0] {
+            let packets = self.number(11)?;
+            for _ in 0..packets {
+                result.push(self.packet()?);
+            }
+        } else {
+            let length = self.number(15)?;
+            let end = self.position + length;
+            if end > self.end {
+                return Err(ParseError::new(
+                    1,
+                    self.end / 4 + 1,
+                    "Transmission ends in the middle of a packet",
+                ));
+            }
+
+            let mut sub_packets = Reader {
+                bits: self.bits,
+                position: self.position,
+                end,
+            };
+            while sub_packets.position < end {
+                result.push(sub_packets.packet()?);
+            }
+
+            self.position = end;
+        }
+
+        // There is no minimum or maximum of nothing
+        if (2..=3).contains(&packet_type_id) && result.is_empty() {
+            return Err(ParseError::new(
+                1,
+                start / 4 + 1,
+                "Minimum and maximum packets need at least 1 sub-packet",
+            ));
+        }
+
+        // Comparisons need exactly two values to compare
+        if (5..=7).contains(&packet_type_id) && result.len() != 2 {
+            return Err(ParseError::new(
+                1,
+                start / 4 + 1,
+                format!(
+                    "Comparison packets need 2 sub-packets, found {}",
+                    result.len()
+                ),
+            ));
+        }
+
+        Ok(PacketContents::Operator(result))
+    }
+}
+
+/// Reads the bits as an unsigned number, most significant bit first.
+pub fn from_bits(input: &[bool]) -> usize {
+    input
+        .iter()
+        .fold(0, |acc, b| (acc << 1) + if *b { 1 } else { 0 })
+}
+
+/// Adds up the version numbers of the packet and all of its sub-packets.
+pub fn version_sum(packet: &Packet) -> usize {
+    packet.version
+        + match &packet.contents {
+            PacketContents::Literal(_) => 0,
+            PacketContents::Operator(contents) => contents.iter().map(version_sum).sum(),
+        }
+}
+
+/// Evaluates the expression the packet describes: sum (0), product (1),
+/// minimum (2), maximum (3), greater than (5), less than (6) and equal to (7).
+///
+/// ## Panics
+///
+/// If a minimum or maximum packet has no sub-packets, or a comparison packet
+/// doesn't have exactly two. [`parse`] rejects both.
+pub fn evaluate(packet: &Packet) -> usize {
+    match &packet.contents {
+        PacketContents::Literal(n) => *n,
+        PacketContents::Operator(contents) => match &packet.packet_type_id {
+            0 => contents.iter().map(evaluate).sum(),
+            1 => contents.iter().map(evaluate).product(),
+            2 => contents
+                .iter()
+                .map(evaluate)
+                .min()
+                .expect("Minimum packets have sub-packets"),
+            3 => contents
+                .iter()
+                .map(evaluate)
+                .max()
+                .expect("Maximum packets have sub-packets"),
+            5 => {
+                if evaluate(&contents[0]) > evaluate(&contents[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+            6 => {
+                if evaluate(&contents[0]) < evaluate(&contents[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+            // Equal to (7) is the only type left, as type 4 is always a literal
+            _ => {
+                if evaluate(&contents[0]) == evaluate(&contents[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        evaluate, from_bits, parse, parse_hexadecimal, version_sum, Packet, PacketContents,
+    };
+
+    fn print_bits(bits: &[bool]) -> String {
+        bits.iter().map(|b| if *b { '1' } else { '0' }).collect()
+    }
+
+    #[test]
+    fn test_parse_hexadecimal() {
+        assert_eq!(
+            "110100101111111000101000",
+            &print_bits(&parse_hexadecimal("D2FE28").unwrap())
+        )
+    }
+
+    #[test]
+    fn test_from_bits() {
+        assert_eq!(1, from_bits(&[true]));
+        assert_eq!(1, from_bits(&[false, false, false, false, false, true]));
+        assert_eq!(6, from_bits(&[true, true, false]));
+        assert_eq!(4, from_bits(&[true, false, false]));
+        assert_eq!(
+            27,
+            from_bits(&[
+                false, false, false, false, false, false, false, false, false, false, true, true,
+                false, true, true,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        assert_eq!(
+            Packet {
+                version: 6,
+                packet_type_id: 4,
+                contents: PacketContents::Literal(2021)
+            },
+            parse("D2FE28").unwrap()
+        );
+        assert_eq!(
+            Packet {
+                version: 1,
+                packet_type_id: 6,
+                contents: PacketContents::Operator(vec![
+                    Packet {
+                        version: 6,
+                        packet_type_id: 4,
+                        contents: PacketContents::Literal(10)
+                    },
+                    Packet {
+                        version: 2,
+                        packet_type_id: 4,
+                        contents: PacketContents::Literal(20)
+                    }
+                ])
+            },
+            parse("38006F45291200").unwrap()
+        );
+        assert_eq!(
+            Packet {
+                version: 7,
+                packet_type_id: 3,
+                contents: PacketContents::Operator(vec![
+                    Packet {
+                        version: 2,
+                        packet_type_id: 4,
+                        contents: PacketContents::Literal(1)
+                    },
+                    Packet {
+                        version: 4,
+                        packet_type_id: 4,
+                        contents: PacketContents::Literal(2)
+                    },
+                    Packet {
+                        version: 1,
+                        packet_type_id: 4,
+                        contents: PacketContents::Literal(3)
+                    },
+                ])
+            },
+            parse("EE00D40C823060").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_version_sum() {
+        assert_eq!(16, version_sum(&parse("8A004A801A8002F478").unwrap()));
+        assert_eq!(
+            12,
+            version_sum(&parse("620080001611562C8802118E34").unwrap())
+        );
+        assert_eq!(
+            23,
+            version_sum(&parse("C0015000016115A2E0802F182340").unwrap())
+        );
+        assert_eq!(
+            31,
+            version_sum(&parse("A0016C880162017C3686B18A3D4780").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(3, evaluate(&parse("C200B40A82").unwrap()));
+        assert_eq!(54, evaluate(&parse("04005AC33890").unwrap()));
+        assert_eq!(7, evaluate(&parse("880086C3E88112").unwrap()));
+        assert_eq!(9, evaluate(&parse("CE00C43D881120").unwrap()));
+        assert_eq!(1, evaluate(&parse("D8005AC2A8F0").unwrap()));
+        assert_eq!(0, evaluate(&parse("F600BC2D8F").unwrap()));
+        assert_eq!(0, evaluate(&parse("9C005AC2F8F0").unwrap()));
+        assert_eq!(1, evaluate(&parse("9C0141080250320F1802104A08").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 1, column 3: Expected a hexadecimal digit, found `G`",
+            parse("D2G").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 5: Transmission ends in the middle of a packet",
+            parse("D2FE").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 10: Transmission ends in the middle of a packet",
+            parse("38006F452").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: Comparison packets need 2 sub-packets, found 0",
+            parse("16000").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: Minimum and maximum packets need at least 1 sub-packet",
+            parse("080000").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: Minimum and maximum packets need at least 1 sub-packet",
+            parse("0C0000").unwrap_err().to_string()
+        );
+    }
+}
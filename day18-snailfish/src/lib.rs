@@ -0,0 +1,405 @@
+//! Day 18: Snailfish.
+//!
+//! Adds up snailfish numbers, which are nested pairs that have to be reduced
+//! by exploding and splitting after every addition.
+
+#![warn(missing_docs)]
+
+use std::{
+    fmt::{self, Display},
+    iter::{Enumerate, Peekable},
+    ops::Add,
+    str::{Chars, FromStr},
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 18.
+pub struct Snailfish;
+
+impl Solution for Snailfish {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Snailfish";
+
+    type Parsed<'a> = Vec<SnailfishNumber>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Vec<SnailfishNumber>) -> Option<u32> {
+        sum(numbers).map(|sum| sum.magnitude())
+    }
+
+    fn part2(numbers: &Vec<SnailfishNumber>) -> Option<u32> {
+        largest_pairwise_magnitude(numbers)
+    }
+}
+
+/// Parses one snailfish number per line.
+pub fn parse_input(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| line.parse().map_err(|e: ParseError| e.on_line(index)))
+        .collect()
+}
+
+/// Adds up all the numbers in order, or returns `None` if there are none.
+pub fn sum(numbers: &[SnailfishNumber]) -> Option<SnailfishNumber> {
+    numbers.iter().cloned().reduce(Add::add)
+}
+
+/// The largest magnitude of the sum of any two different numbers. Snailfish
+/// addition is not commutative, so both orders are tried.
+pub fn largest_pairwise_magnitude(numbers: &[SnailfishNumber]) -> Option<u32> {
+    numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            numbers
+                .iter()
+                .enumerate()
+                .filter(move |(j, _)| i != *j)
+                .map(move |(_, b)| (a.clone() + b.clone()).magnitude())
+        })
+        .max()
+}
+
+/// A snailfish number: either a regular number, or a pair of snailfish numbers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnailfishNumber {
+    /// A plain number, e.g. `7`.
+    Regular(u32),
+    /// A pair, e.g. `[7,[1,2]]`.
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+}
+
+impl SnailfishNumber {
+    /// Creates a pair of two numbers, without reducing it.
+    pub fn pair(left: SnailfishNumber, right: SnailfishNumber) -> Self {
+        Self::Pair(Box::new(left), Box::new(right))
+    }
+
+    /// Three times the magnitude of the left element of each pair, plus twice
+    /// that of the right one. A regular number is its own magnitude.
+    pub fn magnitude(&self) -> u32 {
+        match self {
+            Self::Regular(value) => *value,
+            Self::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    /// Explodes and splits until neither applies anymore. Exploding always
+    /// comes first.
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs, if there is one.
+    /// Its left value is added to the first regular number to its left, its
+    /// right value to the first one to its right, and the pair is replaced
+    /// with 0.
+    ///
+    /// ## Returns
+    ///
+    /// Whether a pair exploded.
+    pub fn explode(&mut self) -> bool {
+        self.explode_at(0).is_some()
+    }
+
+    /// Explodes the leftmost pair at `depth` 4 or deeper.
+    ///
+    /// ## Returns
+    ///
+    /// `None` if nothing exploded, otherwise the values that still need to be
+    /// added to the left and right of this number.
+    fn explode_at(&mut self, depth: usize) -> Option<(Option<u32>, Option<u32>)> {
+        let Self::Pair(left, right) = self else {
+            return None;
+        };
+
+        if depth >= 4 {
+            if let (Self::Regular(left), Self::Regular(right)) = (&**left, &**right) {
+                let carry = (Some(*left), Some(*right));
+                *self = Self::Regular(0);
+
+                return Some(carry);
+            }
+        }
+
+        if let Some((carry_left, carry_right)) = left.explode_at(depth + 1) {
+            if let Some(value) = carry_right {
+                right.add_to_leftmost(value);
+            }
+
+            return Some((carry_left, None));
+        }
+
+        if let Some((carry_left, carry_right)) = right.explode_at(depth + 1) {
+            if let Some(value) = carry_left {
+                left.add_to_rightmost(value);
+            }
+
+            return Some((None, carry_right));
+        }
+
+        None
+    }
+
+    fn add_to_leftmost(&mut self, value: u32) {
+        match self {
+            Self::Regular(regular) => *regular += value,
+            Self::Pair(left, _) => left.add_to_leftmost(value),
+        }
+    }
+
+    fn add_to_rightmost(&mut self, value: u32) {
+        match self {
+            Self::Regular(regular) => *regular += value,
+            Self::Pair(_, right) => right.add_to_rightmost(value),
+        }
+    }
+
+    /// Splits the leftmost regular number of 10 or more into a pair of its
+    /// halves, rounding the left one down and the right one up.
+    ///
+    /// ## Returns
+    ///
+    /// Whether a number was split.
+    pub fn split(&mut self) -> bool {
+        match self {
+            Self::Regular(value) if *value >= 10 => {
+                *self = Self::pair(Self::Regular(*value / 2), Self::Regular(value.div_ceil(2)));
+                true
+            }
+            Self::Regular(_) => false,
+            Self::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    /// Pairs up both numbers, and reduces the result.
+    fn add(self, rhs: SnailfishNumber) -> SnailfishNumber {
+        let mut sum = Self::pair(self, rhs);
+        sum.reduce();
+
+        sum
+    }
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Regular(value) => write!(f, "{}", value),
+            Self::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    /// Parses a single number, such as `[[1,2],3]`. Errors point at line 1.
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut chars = input.chars().enumerate().peekable();
+        let number = parse_internal(input, &mut chars)?;
+
+        match chars.next() {
+            Some((column, c)) => Err(ParseError::new(
+                1,
+                column + 1,
+                format!("Unexpected `{}` after the number", c),
+            )),
+            None => Ok(number),
+        }
+    }
+}
+
+fn parse_internal(
+    input: &str,
+    chars: &mut Peekable<Enumerate<Chars>>,
+) -> Result<SnailfishNumber, ParseError> {
+    match chars.peek().copied() {
+        Some((_, '[')) => {
+            // Consume opening bracket
+            chars.next();
+
+            let left = parse_internal(input, chars)?;
+            expect(input, chars, ',')?;
+            let right = parse_internal(input, chars)?;
+            expect(input, chars, ']')?;
+
+            Ok(SnailfishNumber::pair(left, right))
+        }
+        Some((column, c)) if c.is_ascii_digit() => {
+            let mut value: u32 = 0;
+            while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+                value = value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or_else(|| ParseError::new(1, column + 1, "Number is too large"))?;
+                chars.next();
+            }
+
+            Ok(SnailfishNumber::Regular(value))
+        }
+        Some((column, c)) => Err(ParseError::new(
+            1,
+            column + 1,
+            format!("Expected `[` or a number, found `{}`", c),
+        )),
+        None => Err(ParseError::end_of_input(input, "Expected `[` or a number")),
+    }
+}
+
+/// Consumes the `expected` character.
+fn expect(
+    input: &str,
+    chars: &mut Peekable<Enumerate<Chars>>,
+    expected: char,
+) -> Result<(), ParseError> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((column, c)) => Err(ParseError::new(
+            1,
+            column + 1,
+            format!("Expected `{}`, found `{}`", expected, c),
+        )),
+        None => Err(ParseError::end_of_input(
+            input,
+            format!("Expected `{}`", expected),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{largest_pairwise_magnitude, parse_input, sum, SnailfishNumber};
+
+    const HOMEWORK: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    fn number(input: &str) -> SnailfishNumber {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            SnailfishNumber::pair(
+                SnailfishNumber::pair(SnailfishNumber::Regular(1), SnailfishNumber::Regular(2)),
+                SnailfishNumber::Regular(3)
+            ),
+            number("[[1,2],3]")
+        );
+        assert_eq!(
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+            number("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]").to_string()
+        );
+
+        assert_eq!(
+            "line 2, column 4: Expected `,`, found `]`",
+            parse_input("[1,2]\n[[1]]").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 5: Expected `]`",
+            parse_input("[1,2").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 6: Unexpected `]` after the number",
+            parse_input("[1,2]]").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 4: Expected `[` or a number, found `x`",
+            parse_input("[1,x]").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let mut number = number(before);
+            assert!(number.explode());
+            assert_eq!(after, number.to_string());
+        }
+
+        assert!(!number("[[[[0,9],2],3],4]").explode());
+    }
+
+    #[test]
+    fn split() {
+        let mut number = number("[[[[0,7],4],[15,[0,13]]],[1,1]]");
+        assert!(number.split());
+        assert_eq!("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]", number.to_string());
+        assert!(number.split());
+        assert_eq!("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]", number.to_string());
+        assert!(!number.split());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            (number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]")).to_string()
+        );
+        assert_eq!(
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+            sum(&parse_input("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]").unwrap())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(143, number("[[1,2],[[3,4],5]]").magnitude());
+        assert_eq!(
+            3488,
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude()
+        );
+
+        let sum = sum(&parse_input(HOMEWORK).unwrap()).unwrap();
+        assert_eq!(
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]",
+            sum.to_string()
+        );
+        assert_eq!(4140, sum.magnitude());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Some(3993),
+            largest_pairwise_magnitude(&parse_input(HOMEWORK).unwrap())
+        );
+    }
+}
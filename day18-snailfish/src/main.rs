@@ -0,0 +1,5 @@
+use day18_snailfish::{Snailfish, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<Snailfish>(BUNDLED_INPUT);
+}
@@ -0,0 +1,586 @@
+//! Day 19: Beacon Scanner.
+//!
+//! Pieces together a map of beacons from scanners that each only see the
+//! beacons near them, in their own unknown position and orientation.
+
+#![warn(missing_docs)]
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, Sub},
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 19. Both parts need the full map, so the scanners
+/// are aligned once while parsing, and the map is `None` if they can't be.
+pub struct BeaconScanner;
+
+impl Solution for BeaconScanner {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+
+    type Parsed<'a> = Option<Map>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Option<Map>, ParseError> {
+        parse_input(input).map(|scanners| align(&scanners))
+    }
+
+    fn part1(map: &Option<Map>) -> Option<usize> {
+        map.as_ref().map(|map| map.beacons.len())
+    }
+
+    fn part2(map: &Option<Map>) -> Option<i32> {
+        map.as_ref().and_then(Map::largest_scanner_distance)
+    }
+}
+
+/// How many beacons two scanners need to have in common to be aligned.
+pub const MIN_OVERLAP: usize = 12;
+
+/// A point in space, as `(x, y, z)`. Also used as the offset between two
+/// points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point(pub i32, pub i32, pub i32);
+
+impl Point {
+    /// The Manhattan distance from `(0, 0, 0)`.
+    pub fn manhattan(&self) -> i32 {
+        self.0.abs() + self.1.abs() + self.2.abs()
+    }
+
+    /// The squared straight line distance from `(0, 0, 0)`, which stays the
+    /// same however the point is rotated.
+    pub fn squared_length(&self) -> i64 {
+        [self.0, self.1, self.2]
+            .iter()
+            .map(|&coordinate| coordinate as i64 * coordinate as i64)
+            .sum()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+/// One of the 24 ways a scanner can be oriented, as a rotation matrix: each row
+/// picks one of the axes, possibly flipped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation([[i32; 3]; 3]);
+
+impl Rotation {
+    /// All 24 rotations, starting with the one that leaves points as they are.
+    pub fn all() -> Vec<Rotation> {
+        let mut rotations = vec![];
+        for axes in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            for flips in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, axis) in axes.into_iter().enumerate() {
+                    matrix[row][axis] = if flips >> row & 1 == 1 { -1 } else { 1 };
+                }
+
+                // The other half of the combinations mirror space, which a
+                // scanner can't do by turning around.
+                let rotation = Rotation(matrix);
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+
+        rotations
+    }
+
+    fn determinant(&self) -> i32 {
+        let m = self.0;
+
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Rotates `point` around `(0, 0, 0)`.
+    pub fn apply(&self, point: Point) -> Point {
+        let coordinates = [point.0, point.1, point.2];
+        let row = |row: [i32; 3]| {
+            row.iter()
+                .zip(coordinates)
+                .map(|(factor, coordinate)| factor * coordinate)
+                .sum()
+        };
+
+        Point(row(self.0[0]), row(self.0[1]), row(self.0[2]))
+    }
+
+    /// The rotation that undoes this one.
+    pub fn inverse(&self) -> Rotation {
+        let mut transposed = [[0; 3]; 3];
+        for (row, values) in self.0.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                transposed[column][row] = *value;
+            }
+        }
+
+        Rotation(transposed)
+    }
+}
+
+/// The beacons a scanner can see, relative to its own position and
+/// orientation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scanner {
+    /// The number in the scanner's header.
+    pub number: usize,
+    /// The beacons the scanner sees.
+    pub beacons: Vec<Point>,
+}
+
+/// How to turn positions seen by one scanner into positions seen by another:
+/// rotate them, then move them by `position`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alignment {
+    /// The orientation of the scanner.
+    pub rotation: Rotation,
+    /// Where the scanner is.
+    pub position: Point,
+}
+
+impl Alignment {
+    /// Moves a point from the aligned scanner's view into the other one's.
+    pub fn apply(&self, point: Point) -> Point {
+        self.rotation.apply(point) + self.position
+    }
+}
+
+/// Every beacon and scanner, relative to the first scanner.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    /// Every beacon seen by any of the scanners.
+    pub beacons: HashSet<Point>,
+    /// The position of each scanner, in the order they were given in.
+    pub scanners: Vec<Point>,
+}
+
+impl Map {
+    /// The largest Manhattan distance between any two scanners.
+    pub fn largest_scanner_distance(&self) -> Option<i32> {
+        self.scanners
+            .iter()
+            .flat_map(|&a| self.scanners.iter().map(move |&b| (a - b).manhattan()))
+            .max()
+    }
+}
+
+/// Parses every scanner's report: a header such as `--- scanner 0 ---`,
+/// followed by a beacon per line, such as `-618,-824,-621`. Reports are
+/// separated by blank lines.
+pub fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    let mut scanners: Vec<Scanner> = vec![];
+    let mut in_report = false;
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            in_report = false;
+            continue;
+        }
+
+        if !in_report {
+            let number = line
+                .strip_prefix("--- scanner ")
+                .and_then(|line| line.strip_suffix(" ---"))
+                .ok_or_else(|| {
+                    ParseError::at(
+                        index,
+                        line,
+                        line,
+                        "Expected a scanner header, such as `--- scanner 0 ---`",
+                    )
+                })?;
+
+            scanners.push(Scanner {
+                number: aoc_core::parse::number(index, line, number)?,
+                beacons: vec![],
+            });
+            in_report = true;
+            continue;
+        }
+
+        let coordinates: Vec<_> = line.split(',').collect();
+        let [x, y, z] = coordinates[..] else {
+            return Err(ParseError::at(
+                index,
+                line,
+                line,
+                "Expected a beacon, such as `-618,-824,-621`",
+            ));
+        };
+        let coordinate = |part| aoc_core::parse::number(index, line, part);
+
+        if let Some(scanner) = scanners.last_mut() {
+            scanner
+                .beacons
+                .push(Point(coordinate(x)?, coordinate(y)?, coordinate(z)?));
+        }
+    }
+
+    Ok(scanners)
+}
+
+/// How often each squared distance occurs between any two of the `beacons`.
+/// Distances don't depend on the scanner's position or orientation, so
+/// scanners that see the same beacons share a lot of them.
+pub fn fingerprint(beacons: &[Point]) -> HashMap<i64, usize> {
+    let mut fingerprint = HashMap::new();
+    for (i, a) in beacons.iter().enumerate() {
+        for b in &beacons[i + 1..] {
+            *fingerprint.entry((*a - *b).squared_length()).or_default() += 1;
+        }
+    }
+
+    fingerprint
+}
+
+/// The number of distances two fingerprints have in common.
+fn shared_distances(a: &HashMap<i64, usize>, b: &HashMap<i64, usize>) -> usize {
+    a.iter()
+        .map(|(distance, count)| b.get(distance).map_or(0, |other| *count.min(other)))
+        .sum()
+}
+
+/// Finds how `other` is positioned relative to the scanner that sees the
+/// `known` beacons, if they have at least [`MIN_OVERLAP`] beacons in common.
+pub fn find_alignment(known: &[Point], other: &[Point]) -> Option<Alignment> {
+    for rotation in Rotation::all() {
+        // Every pair of beacons votes for the position the other scanner
+        // would have if they were the same beacon.
+        let mut votes = HashMap::new();
+        for &a in known {
+            for &b in other {
+                let position = a - rotation.apply(b);
+                let count = votes.entry(position).or_insert(0);
+                *count += 1;
+
+                if *count >= MIN_OVERLAP {
+                    return Some(Alignment { rotation, position });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Aligns every scanner with the first one, by repeatedly aligning unaligned
+/// scanners with those that already are. Only pairs of scanners whose
+/// fingerprints share enough distances are tried.
+///
+/// ## Returns
+///
+/// The resulting map, or `None` if not every scanner can be aligned.
+pub fn align(scanners: &[Scanner]) -> Option<Map> {
+    // Scanners that see the same 12 beacons share (12 * 11) / 2 distances
+    let min_shared_distances = MIN_OVERLAP * (MIN_OVERLAP - 1) / 2;
+    let fingerprints: Vec<_> = scanners
+        .iter()
+        .map(|scanner| fingerprint(&scanner.beacons))
+        .collect();
+
+    let mut beacons: Vec<Option<Vec<Point>>> = vec![None; scanners.len()];
+    let mut positions = vec![None; scanners.len()];
+    beacons[0] = Some(scanners.first()?.beacons.clone());
+    positions[0] = Some(Point(0, 0, 0));
+
+    let mut aligned = vec![0];
+    while let Some(known) = aligned.pop() {
+        let known_beacons = beacons[known].clone()?;
+
+        for (other, scanner) in scanners.iter().enumerate() {
+            if positions[other].is_some()
+                || shared_distances(&fingerprints[known], &fingerprints[other])
+                    < min_shared_distances
+            {
+                continue;
+            }
+
+            if let Some(alignment) = find_alignment(&known_beacons, &scanner.beacons) {
+                beacons[other] = Some(
+                    scanner
+                        .beacons
+                        .iter()
+                        .map(|&beacon| alignment.apply(beacon))
+                        .collect(),
+                );
+                positions[other] = Some(alignment.position);
+                aligned.push(other);
+            }
+        }
+    }
+
+    Some(Map {
+        beacons: beacons
+            .into_iter()
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect(),
+        scanners: positions.into_iter().collect::<Option<_>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::Solution;
+
+    use crate::{align, find_alignment, parse_input, BeaconScanner, Point, Rotation, Scanner};
+
+    const TEST_INPUT: &str = "--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14";
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(vec![
+                Scanner {
+                    number: 0,
+                    beacons: vec![Point(404, -588, -901), Point(528, -643, 409)],
+                },
+                Scanner {
+                    number: 1,
+                    beacons: vec![Point(686, 422, 578)],
+                },
+            ]),
+            parse_input(
+                "--- scanner 0 ---
+404,-588,-901
+528,-643,409
+
+--- scanner 1 ---
+686,422,578"
+            )
+        );
+        assert_eq!(
+            "line 1, column 1: Expected a scanner header, such as `--- scanner 0 ---`",
+            parse_input("404,-588,-901").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 1: Expected a beacon, such as `-618,-824,-621`",
+            parse_input("--- scanner 0 ---\n404,-588")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(24, rotations.len());
+
+        let point = Point(1, 2, 3);
+        assert_eq!(point, rotations[0].apply(point));
+
+        let rotated: HashSet<_> = rotations.iter().map(|r| r.apply(point)).collect();
+        assert_eq!(24, rotated.len());
+        for rotation in rotations {
+            assert_eq!(point, rotation.inverse().apply(rotation.apply(point)));
+        }
+    }
+
+    #[test]
+    fn alignment() {
+        let scanners = parse_input(TEST_INPUT).unwrap();
+
+        let alignment = find_alignment(&scanners[0].beacons, &scanners[1].beacons).unwrap();
+        assert_eq!(Point(68, -1246, -43), alignment.position);
+        assert_eq!(
+            Point(-618, -824, -621),
+            alignment.rotation.apply(Point(686, 422, 578)) + alignment.position
+        );
+
+        assert_eq!(
+            None,
+            find_alignment(&scanners[0].beacons[..11], &scanners[1].beacons)
+        );
+    }
+
+    #[test]
+    fn both_parts() {
+        let map = align(&parse_input(TEST_INPUT).unwrap()).unwrap();
+
+        assert_eq!(
+            vec![
+                Point(0, 0, 0),
+                Point(68, -1246, -43),
+                Point(1105, -1205, 1229),
+                Point(-92, -2380, -20),
+                Point(-20, -1133, 1061),
+            ],
+            map.scanners
+        );
+        assert_eq!(79, map.beacons.len());
+        assert_eq!(Some(3621), map.largest_scanner_distance());
+
+        let parsed = BeaconScanner::parse(TEST_INPUT).unwrap();
+        assert_eq!(Some(79), BeaconScanner::part1(&parsed));
+        assert_eq!(Some(3621), BeaconScanner::part2(&parsed));
+    }
+}
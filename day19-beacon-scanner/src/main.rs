@@ -0,0 +1,5 @@
+use day19_beacon_scanner::{BeaconScanner, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<BeaconScanner>(BUNDLED_INPUT);
+}
@@ -0,0 +1,263 @@
+//! Day 2: Dive!
+//!
+//! Follows the planned course of the submarine, under two different
+//! interpretations of what its instructions mean.
+
+#![warn(missing_docs)]
+
+pub mod trajectory;
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 2.
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Parsed<'a> = Vec<Instruction>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Option<isize> {
+        Some(calculate_position_product(instructions))
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Option<isize> {
+        Some(calculate_position_product_part2(instructions))
+    }
+}
+
+/// A single step of the planned course.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// Increases the depth (or, in part 2, the aim).
+    Down(isize),
+    /// Moves forward horizontally (and, in part 2, dives according to the aim).
+    Forward(isize),
+    /// Decreases the depth (or, in part 2, the aim).
+    Up(isize),
+}
+
+/// Where the submarine is, and where it's heading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Submarine {
+    /// How far it has moved forward.
+    pub horizontal_position: isize,
+    /// How deep it is.
+    pub depth: isize,
+    /// How much deeper it gets per unit it moves forward, for models that use
+    /// it.
+    pub aim: isize,
+}
+
+impl Submarine {
+    /// Carries out a single instruction, as interpreted by `model`.
+    pub fn apply(&mut self, model: &impl SteeringModel, instruction: Instruction) {
+        model.steer(self, instruction);
+    }
+
+    /// Starts at the surface and follows the whole course.
+    pub fn follow(model: &impl SteeringModel, instructions: &[Instruction]) -> Submarine {
+        let mut submarine = Submarine::default();
+        for &instruction in instructions {
+            submarine.apply(model, instruction);
+        }
+
+        submarine
+    }
+
+    /// The horizontal position multiplied by the depth.
+    pub fn position_product(&self) -> isize {
+        self.horizontal_position * self.depth
+    }
+}
+
+/// An interpretation of what the instructions mean.
+pub trait SteeringModel {
+    /// Changes the submarine's state according to the instruction.
+    fn steer(&self, submarine: &mut Submarine, instruction: Instruction);
+}
+
+/// Up and down change the depth directly (part 1).
+#[derive(Clone, Copy, Debug)]
+pub struct DirectSteering;
+
+impl SteeringModel for DirectSteering {
+    fn steer(&self, submarine: &mut Submarine, instruction: Instruction) {
+        match instruction {
+            Instruction::Down(amount) => submarine.depth += amount,
+            Instruction::Forward(amount) => submarine.horizontal_position += amount,
+            Instruction::Up(amount) => submarine.depth -= amount,
+        }
+    }
+}
+
+/// Up and down change the aim, and moving forward dives by the aim (part 2).
+#[derive(Clone, Copy, Debug)]
+pub struct AimSteering;
+
+impl SteeringModel for AimSteering {
+    fn steer(&self, submarine: &mut Submarine, instruction: Instruction) {
+        match instruction {
+            Instruction::Down(amount) => submarine.aim += amount,
+            Instruction::Forward(amount) => {
+                submarine.horizontal_position += amount;
+                submarine.depth += amount * submarine.aim;
+            }
+            Instruction::Up(amount) => submarine.aim -= amount,
+        }
+    }
+}
+
+/// Follows the course where up and down change the depth directly, and returns
+/// the final horizontal position multiplied by the final depth.
+pub fn calculate_position_product(instructions: &[Instruction]) -> isize {
+    Submarine::follow(&DirectSteering, instructions).position_product()
+}
+
+/// Follows the course where up and down change the aim, and moving forward
+/// dives by the aim. Returns the final horizontal position multiplied by the
+/// final depth.
+pub fn calculate_position_product_part2(instructions: &[Instruction]) -> isize {
+    Submarine::follow(&AimSteering, instructions).position_product()
+}
+
+/// Parses the planned course, one instruction per line.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_instruction(line).map_err(|e| e.on_line(index)))
+        .collect()
+}
+
+/// Parses an instruction such as `forward 5`. Errors point at line 1.
+pub fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let (direction, amount) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(1, 1, "Expected a direction and an amount"))?;
+    let amount = aoc_core::parse::number(0, line, amount)?;
+
+    match direction {
+        "down" => Ok(Instruction::Down(amount)),
+        "forward" => Ok(Instruction::Forward(amount)),
+        "up" => Ok(Instruction::Up(amount)),
+        _ => Err(ParseError::at(
+            0,
+            line,
+            direction,
+            format!("Unknown direction `{}`", direction),
+        )),
+    }
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        150,
+        calculate_position_product(
+            &parse_input(
+                "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"
+            )
+            .unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        900,
+        calculate_position_product_part2(
+            &parse_input(
+                "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"
+            )
+            .unwrap()
+        )
+    )
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+#[test]
+fn test_submarine() {
+    let instructions = parse_input(TEST_INPUT).unwrap();
+
+    let mut submarine = Submarine::default();
+    submarine.apply(&AimSteering, instructions[0]);
+    submarine.apply(&AimSteering, instructions[1]);
+    submarine.apply(&AimSteering, instructions[2]);
+    assert_eq!(
+        Submarine {
+            horizontal_position: 13,
+            depth: 40,
+            aim: 5,
+        },
+        submarine
+    );
+
+    assert_eq!(
+        Submarine {
+            horizontal_position: 15,
+            depth: 10,
+            aim: 0,
+        },
+        Submarine::follow(&DirectSteering, &instructions)
+    );
+}
+
+#[test]
+fn test_custom_model() {
+    /// Direct steering that can't dive below a maximum depth.
+    struct MaxDepth(isize);
+
+    impl SteeringModel for MaxDepth {
+        fn steer(&self, submarine: &mut Submarine, instruction: Instruction) {
+            DirectSteering.steer(submarine, instruction);
+            submarine.depth = submarine.depth.min(self.0);
+        }
+    }
+
+    let submarine = Submarine::follow(&MaxDepth(6), &parse_input(TEST_INPUT).unwrap());
+    assert_eq!(15, submarine.horizontal_position);
+    assert_eq!(6, submarine.depth);
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 2, column 1: Unknown direction `backward`",
+        parse_input("forward 5\nbackward 2")
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 1, column 6: Expected a number, found `five`",
+        parse_input("down five").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,211 @@
+//! Records the path the submarine takes, and exports it as CSV or as an SVG
+//! depth profile.
+
+use std::fmt::Write;
+
+use crate::{Instruction, SteeringModel, Submarine};
+
+/// The submarine's state after a number of steps of the course.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Waypoint {
+    /// The number of instructions carried out so far.
+    pub step: usize,
+    /// The state after those instructions.
+    pub submarine: Submarine,
+}
+
+/// Follows the course like [`Submarine::follow`], but keeps the state after
+/// every instruction, starting with the submarine at the surface at step 0.
+pub fn record(model: &impl SteeringModel, instructions: &[Instruction]) -> Vec<Waypoint> {
+    let mut submarine = Submarine::default();
+    let mut trajectory = vec![Waypoint { step: 0, submarine }];
+
+    for (index, &instruction) in instructions.iter().enumerate() {
+        submarine.apply(model, instruction);
+        trajectory.push(Waypoint {
+            step: index + 1,
+            submarine,
+        });
+    }
+
+    trajectory
+}
+
+/// Renders the trajectory as CSV, with one row per waypoint.
+pub fn as_csv(trajectory: &[Waypoint]) -> String {
+    let mut csv = "step,horizontal,depth,aim\n".to_owned();
+    for waypoint in trajectory {
+        let submarine = &waypoint.submarine;
+        writeln!(
+            csv,
+            "{},{},{},{}",
+            waypoint.step, submarine.horizontal_position, submarine.depth, submarine.aim
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+/// The size of the SVG image, and the space around the plot.
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 300.0;
+const MARGIN: f64 = 20.0;
+
+/// The line colours, used in turn for each trajectory.
+const COLOURS: [&str; 4] = ["#1f77b4", "#d62728", "#2ca02c", "#9467bd"];
+
+/// Renders a depth profile of each named trajectory as an SVG image, with
+/// depth increasing downwards from a line at the surface. All trajectories
+/// share the same scale, so they can be compared directly.
+pub fn depth_profile_svg(trajectories: &[(&str, &[Waypoint])]) -> String {
+    let submarines = || {
+        trajectories
+            .iter()
+            .flat_map(|(_, trajectory)| trajectory.iter().map(|waypoint| waypoint.submarine))
+    };
+    let min_horizontal = submarines()
+        .map(|submarine| submarine.horizontal_position)
+        .fold(0, isize::min);
+    let max_horizontal = submarines()
+        .map(|submarine| submarine.horizontal_position)
+        .fold(0, isize::max);
+    let min_depth = submarines()
+        .map(|submarine| submarine.depth)
+        .fold(0, isize::min);
+    let max_depth = submarines()
+        .map(|submarine| submarine.depth)
+        .fold(0, isize::max);
+
+    let x = |horizontal: isize| {
+        MARGIN
+            + (horizontal - min_horizontal) as f64 / (max_horizontal - min_horizontal).max(1) as f64
+                * (WIDTH - 2.0 * MARGIN)
+    };
+    let y = |depth: isize| {
+        MARGIN
+            + (depth - min_depth) as f64 / (max_depth - min_depth).max(1) as f64
+                * (HEIGHT - 2.0 * MARGIN)
+    };
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        WIDTH, HEIGHT
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r##"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="#999" stroke-dasharray="4"/>"##,
+        x(min_horizontal),
+        y(0),
+        x(max_horizontal),
+        y(0)
+    )
+    .unwrap();
+
+    for (index, (name, trajectory)) in trajectories.iter().enumerate() {
+        let colour = COLOURS[index % COLOURS.len()];
+        let points: Vec<_> = trajectory
+            .iter()
+            .map(|waypoint| {
+                format!(
+                    "{:.1},{:.1}",
+                    x(waypoint.submarine.horizontal_position),
+                    y(waypoint.submarine.depth)
+                )
+            })
+            .collect();
+
+        writeln!(
+            svg,
+            r#"  <polyline points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+            points.join(" "),
+            colour
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"  <text x="{:.1}" y="{:.1}" fill="{}" font-family="sans-serif" font-size="12" text-anchor="end">{}</text>"#,
+            WIDTH - MARGIN,
+            MARGIN + 14.0 * index as f64,
+            colour,
+            escape(name)
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Escapes the characters that would otherwise be read as XML markup.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_input,
+        trajectory::{as_csv, depth_profile_svg, record},
+        AimSteering, DirectSteering, Submarine, TEST_INPUT,
+    };
+
+    #[test]
+    fn recording() {
+        let instructions = parse_input(TEST_INPUT).unwrap();
+        let trajectory = record(&AimSteering, &instructions);
+
+        assert_eq!(7, trajectory.len());
+        assert_eq!(Submarine::default(), trajectory[0].submarine);
+        assert_eq!(
+            Submarine::follow(&AimSteering, &instructions),
+            trajectory[6].submarine
+        );
+        assert_eq!(
+            "step,horizontal,depth,aim
+0,0,0,0
+1,5,0,0
+2,5,0,5
+3,13,40,5
+4,13,40,2
+5,13,40,10
+6,15,60,10
+",
+            as_csv(&trajectory)
+        );
+    }
+
+    #[test]
+    fn svg() {
+        let instructions = parse_input("forward 2\ndown 1\nforward 2").unwrap();
+        let direct = record(&DirectSteering, &instructions);
+        let aim = record(&AimSteering, &instructions);
+
+        assert_eq!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="600" height="300" viewBox="0 0 600 300">
+  <line x1="20.0" y1="20.0" x2="580.0" y2="20.0" stroke="#999" stroke-dasharray="4"/>
+  <polyline points="20.0,20.0 300.0,20.0 300.0,150.0 580.0,150.0" fill="none" stroke="#1f77b4" stroke-width="2"/>
+  <text x="580.0" y="20.0" fill="#1f77b4" font-family="sans-serif" font-size="12" text-anchor="end">direct</text>
+  <polyline points="20.0,20.0 300.0,20.0 300.0,20.0 580.0,280.0" fill="none" stroke="#d62728" stroke-width="2"/>
+  <text x="580.0" y="34.0" fill="#d62728" font-family="sans-serif" font-size="12" text-anchor="end">aim &amp; dive</text>
+</svg>
+"##,
+            depth_profile_svg(&[("direct", &direct), ("aim & dive", &aim)])
+        );
+    }
+
+    #[test]
+    fn svg_backwards() {
+        // Moving backwards still keeps the whole course on the canvas
+        let instructions = parse_input("forward -5\ndown 2\nforward 5").unwrap();
+        let svg = depth_profile_svg(&[("direct", &record(&DirectSteering, &instructions))]);
+
+        assert!(svg.contains(r#"<line x1="20.0" y1="20.0" x2="580.0" y2="20.0""#));
+        assert!(svg.contains(r#"<polyline points="580.0,20.0 20.0,20.0 20.0,280.0 580.0,280.0""#));
+    }
+}
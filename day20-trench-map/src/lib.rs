@@ -0,0 +1,301 @@
+//! Day 20: Trench Map.
+//!
+//! Enhances an infinite image, replacing every pixel based on the 3x3 square of
+//! pixels around it.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::Grid;
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 20.
+pub struct TrenchMap;
+
+impl Solution for TrenchMap {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Trench Map";
+
+    type Parsed<'a> = (Algorithm, Image);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((algorithm, image): &Self::Parsed<'_>) -> Option<usize> {
+        enhance_times(image, algorithm, 2).lit_count()
+    }
+
+    fn part2((algorithm, image): &Self::Parsed<'_>) -> Option<usize> {
+        enhance_times(image, algorithm, 50).lit_count()
+    }
+}
+
+/// Whether a pixel is lit, for each of the 512 possible 3x3 squares around
+/// it. The square is read row by row as a binary number, with lit pixels as 1.
+pub type Algorithm = [bool; 512];
+
+/// An infinite image: a grid of pixels, surrounded by pixels that all have the
+/// same value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    /// The pixels that can differ from the background, `true` if lit.
+    pub pixels: Grid<bool>,
+    /// Whether every pixel outside of `pixels` is lit. It can be, if the
+    /// algorithm lights up pixels surrounded by unlit ones.
+    pub background: bool,
+}
+
+impl Image {
+    /// Whether the pixel at `(x, y)` is lit, which may be outside of the grid.
+    pub fn pixel(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 {
+            return self.background;
+        }
+
+        self.pixels
+            .get((x as usize, y as usize))
+            .copied()
+            .unwrap_or(self.background)
+    }
+
+    /// Applies the algorithm once. Pixels next to the grid can change too, so
+    /// the enhanced grid is one pixel larger on every side.
+    pub fn enhance(&self, algorithm: &Algorithm) -> Image {
+        let width = self.pixels.width() + 2;
+        let height = self.pixels.height() + 2;
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for y in 0..height as isize {
+            // The square around the pixel to the left of the row, which is
+            // background. The pixels of the old grid are offset by 1.
+            let mut index = if self.background { 0b111_111_111 } else { 0 };
+
+            for x in 0..width as isize {
+                // Shift the square one to the right, keeping the two columns it
+                // still covers and adding the new one.
+                let column = (self.pixel(x, y - 2) as usize) << 6
+                    | (self.pixel(x, y - 1) as usize) << 3
+                    | self.pixel(x, y) as usize;
+                index = (index << 1) & 0b110_110_110 | column;
+
+                pixels.push(algorithm[index]);
+            }
+        }
+
+        Image {
+            pixels: Grid::new(width, height, pixels),
+            background: algorithm[if self.background { 511 } else { 0 }],
+        }
+    }
+
+    /// The number of lit pixels, or `None` if the background is lit and there
+    /// are infinitely many.
+    pub fn lit_count(&self) -> Option<usize> {
+        if self.background {
+            None
+        } else {
+            Some(self.pixels.iter().filter(|&&lit| lit).count())
+        }
+    }
+}
+
+/// Applies the algorithm `passes` times.
+pub fn enhance_times(image: &Image, algorithm: &Algorithm, passes: usize) -> Image {
+    (0..passes).fold(image.clone(), |image, _| image.enhance(algorithm))
+}
+
+/// Renders the grid part of the image, with lit pixels as `#` and unlit ones as
+/// `.`, one line per row.
+pub fn as_text(image: &Image) -> String {
+    image
+        .pixels
+        .map(|&lit| if lit { '#' } else { '.' })
+        .to_string()
+}
+
+/// Parses the algorithm as a line of 512 `#` and `.`, followed by a blank line
+/// and the image. The image starts out on an unlit background.
+pub fn parse_input(input: &str) -> Result<(Algorithm, Image), ParseError> {
+    let pixel = |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    };
+
+    let (line, image) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of_input(input, "Expected a blank line and an image"))?;
+
+    let mut algorithm = [false; 512];
+    let mut length = 0;
+    for (column, c) in line.chars().enumerate() {
+        let lit = pixel(c).ok_or_else(|| {
+            ParseError::new(1, column + 1, format!("Unexpected character `{}`", c))
+        })?;
+        if let Some(entry) = algorithm.get_mut(column) {
+            *entry = lit;
+        }
+        length += 1;
+    }
+    if length != 512 {
+        return Err(ParseError::new(
+            1,
+            length.min(512) + 1,
+            format!("The algorithm is {} pixels long, expected 512", length),
+        ));
+    }
+
+    // The image starts on line 3, which has an index of 2
+    let pixels = Grid::parse_with(image, pixel).map_err(|e| {
+        let index = e.line() + 1;
+        e.on_line(index)
+    })?;
+
+    Ok((
+        algorithm,
+        Image {
+            pixels,
+            background: false,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::Grid;
+
+    use crate::{as_text, enhance_times, parse_input, Algorithm, Image};
+
+    // The algorithm is a single line, split up here to keep it readable
+    const TEST_INPUT: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#.\
+        .#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..\
+        #.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....\
+        #.#....###..#.##......#.....#..#..#..##..#...##.######.####.####\
+        .#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.\
+        #.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..\
+        #.##.#....##..#.####....##...##..#...#......#.#.......#.......##\
+        ..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    /// An algorithm that lights a pixel based on the index of the square
+    /// around it.
+    fn algorithm(lit: impl Fn(usize) -> bool) -> Algorithm {
+        std::array::from_fn(lit)
+    }
+
+    fn image(pixels: &str) -> Image {
+        Image {
+            pixels: Grid::parse_with(pixels, |c| Some(c == '#')).unwrap(),
+            background: false,
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let input = format!("#{}\n\n#.\n.#", ".".repeat(511));
+        let (algorithm, image) = parse_input(&input).unwrap();
+        assert!(algorithm[0]);
+        assert!(!algorithm[511]);
+        assert_eq!("#.\n.#", as_text(&image));
+
+        assert_eq!(
+            "line 1, column 4: The algorithm is 3 pixels long, expected 512",
+            parse_input("#..\n\n#.").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 4, column 2: Unexpected character `x`",
+            parse_input(&format!("{}\n\n#.\n.x", ".".repeat(512)))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn example() {
+        let (algorithm, image) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(Some(35), enhance_times(&image, &algorithm, 2).lit_count());
+        assert_eq!(
+            Some(3351),
+            enhance_times(&image, &algorithm, 50).lit_count()
+        );
+    }
+
+    #[test]
+    fn enhance_identity() {
+        // Only the middle pixel of the square counts
+        let algorithm = algorithm(|index| index & 0b000_010_000 != 0);
+        let image = image("#..\n.#.\n#.#");
+
+        let enhanced = enhance_times(&image, &algorithm, 2);
+        assert_eq!(
+            ".......\n.......\n..#....\n...#...\n..#.#..\n.......\n.......",
+            as_text(&enhanced)
+        );
+        assert_eq!(Some(4), enhanced.lit_count());
+    }
+
+    #[test]
+    fn enhance_grow() {
+        // Any lit pixel in the square lights the middle one
+        let algorithm = algorithm(|index| index != 0);
+        let image = image("#");
+
+        assert_eq!("###\n###\n###", as_text(&image.enhance(&algorithm)));
+        assert_eq!(Some(121), enhance_times(&image, &algorithm, 5).lit_count());
+    }
+
+    #[test]
+    fn enhance_shift() {
+        // Copies the top left pixel of the square, moving the image down and
+        // right
+        let algorithm = algorithm(|index| index & 0b100_000_000 != 0);
+        let image = image("##\n#.");
+
+        assert_eq!(
+            "....\n....\n..##\n..#.",
+            as_text(&image.enhance(&algorithm))
+        );
+        assert_eq!(
+            "......\n......\n......\n......\n....##\n....#.",
+            as_text(&enhance_times(&image, &algorithm, 2))
+        );
+    }
+
+    #[test]
+    fn flickering_background() {
+        // Unlit squares light up, fully lit ones go dark, and everything else
+        // keeps its middle pixel
+        let algorithm = algorithm(|index| match index {
+            0 => true,
+            511 => false,
+            _ => index & 0b000_010_000 != 0,
+        });
+        let image = image("...\n.#.\n...");
+
+        let once = image.enhance(&algorithm);
+        assert!(once.background);
+        assert_eq!(None, once.lit_count());
+        assert_eq!("#####\n#...#\n#.#.#\n#...#\n#####", as_text(&once));
+
+        let twice = once.enhance(&algorithm);
+        assert!(!twice.background);
+        assert_eq!(
+            ".......\n.#####.\n.#...#.\n.#.#.#.\n.#...#.\n.#####.\n.......",
+            as_text(&twice)
+        );
+        assert_eq!(Some(17), twice.lit_count());
+        assert!(enhance_times(&image, &algorithm, 50).lit_count().is_some());
+        assert_eq!(None, enhance_times(&image, &algorithm, 51).lit_count());
+    }
+}
@@ -0,0 +1,5 @@
+use day20_trench_map::{TrenchMap, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<TrenchMap>(BUNDLED_INPUT);
+}
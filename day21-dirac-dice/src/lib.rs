@@ -0,0 +1,312 @@
+//! Day 21: Dirac Dice.
+//!
+//! Plays a game of Dirac Dice, first with a deterministic die and then with a
+//! quantum die that splits the universe on every roll.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, sync::Mutex};
+
+use aoc_core::{ParseError, Solution};
+use lazy_static::lazy_static;
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 21.
+pub struct DiracDice;
+
+impl Solution for DiracDice {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+
+    type Parsed<'a> = [Player; 2];
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<[Player; 2], ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(players: &[Player; 2]) -> Option<u32> {
+        let mut players = players.clone();
+        let mut die = DeterministicDie::new();
+
+        let winner = simulate_deterministic(&mut players, &mut die, 1000);
+        let loser = find_losing_player(&players, winner)?;
+
+        Some(loser.score * die.rolls)
+    }
+
+    fn part2(players: &[Player; 2]) -> Option<u64> {
+        let mut players = players.clone();
+        let outcomes = simulate_quantum(&mut players, &mut QuantumDie, 0, 21);
+
+        outcomes.iter().max().copied()
+    }
+}
+
+/// Parses both players' starting positions, e.g.
+/// `Player 1 starting position: 4`.
+pub fn parse_input(input: &str) -> Result<[Player; 2], ParseError> {
+    let mut positions = input.lines().enumerate().map(|(index, line)| {
+        let (_, position) = line
+            .strip_prefix("Player ")
+            .and_then(|line| line.split_once(" starting position: "))
+            .ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Expected a starting position, such as `Player 1 starting position: 4`",
+                )
+            })?;
+
+        match aoc_core::parse::number(index, line, position.trim_end())? {
+            position @ 1..=10 => Ok(position),
+            _ => Err(ParseError::at(
+                index,
+                line,
+                position,
+                "Positions go from 1 to 10",
+            )),
+        }
+    });
+    let mut next_player = || {
+        positions
+            .next()
+            .unwrap_or_else(|| Err(ParseError::end_of_input(input, "Expected two players")))
+            .map(Player::new)
+    };
+
+    Ok([next_player()?, next_player()?])
+}
+
+/// Finds the player that did not win, given the index of the `winner`.
+pub fn find_losing_player(players: &[Player], winner: usize) -> Option<&Player> {
+    players
+        .iter()
+        .enumerate()
+        .filter_map(|(index, player)| if index != winner { Some(player) } else { None })
+        .next()
+}
+
+/// Plays the game with a deterministic die until a player reaches the
+/// `winning_score`.
+///
+/// ## Returns
+///
+/// The index of the winning player.
+pub fn simulate_deterministic(
+    players: &mut [Player; 2],
+    die: &mut DeterministicDie,
+    winning_score: u32,
+) -> usize {
+    'outer: loop {
+        for (index, player) in players.iter_mut().enumerate() {
+            player.move_pawn(die.roll() + die.roll() + die.roll());
+            if player.score >= winning_score {
+                break 'outer index;
+            }
+        }
+    }
+}
+
+type QuantumLookupType = ([Player; 2], QuantumDie, usize, u32);
+
+lazy_static! {
+    static ref MEMOIZED: Mutex<HashMap<QuantumLookupType, [u64; 2]>> = Default::default();
+}
+
+/// Plays the game with a quantum die, starting at `current_turn`, in every
+/// possible universe.
+///
+/// ## Returns
+///
+/// The number of universes in which each player wins.
+pub fn simulate_quantum(
+    players: &mut [Player; 2],
+    die: &mut QuantumDie,
+    current_turn: usize,
+    winning_score: u32,
+) -> [u64; 2] {
+    let lookup_key = (players.clone(), die.clone(), current_turn, winning_score);
+    if let Some(previously_calculated) = MEMOIZED.lock().unwrap().get(&lookup_key) {
+        return *previously_calculated;
+    }
+
+    let mut wins = [0; 2];
+    for (i, p) in players.iter().enumerate() {
+        if p.score >= winning_score {
+            wins[i] += 1;
+
+            MEMOIZED.lock().unwrap().insert(lookup_key, wins);
+            return wins;
+        }
+    }
+
+    for outcome in die.three_roll_sums() {
+        let mut cloned_players = players.clone();
+        cloned_players[current_turn].move_pawn(outcome);
+
+        // Explore each possible outcome
+        for (player, wins_found) in simulate_quantum(
+            &mut cloned_players,
+            &mut die.clone(),
+            (current_turn + 1) % players.len(),
+            winning_score,
+        )
+        .iter()
+        .enumerate()
+        {
+            wins[player] += wins_found;
+        }
+    }
+
+    MEMOIZED.lock().unwrap().insert(lookup_key, wins);
+
+    wins
+}
+
+/// A player's pawn position on the circular track (1-10), and their score.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Player {
+    /// Position on the track, from 1 to 10.
+    pub position: u32,
+    /// Sum of all positions landed on so far.
+    pub score: u32,
+}
+
+impl Player {
+    /// Creates a player at `position`, with a score of 0.
+    pub fn new(position: u32) -> Self {
+        Self { position, score: 0 }
+    }
+
+    fn move_pawn(&mut self, spaces: u32) {
+        self.position = ((self.position - 1 + spaces) % 10) + 1;
+        self.score += self.position;
+    }
+}
+
+/// A 100-sided die that always rolls 1, 2, 3, ... 100, 1, 2, ...
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeterministicDie {
+    state: u32,
+    /// How many times the die has been rolled.
+    pub rolls: u32,
+}
+
+impl DeterministicDie {
+    /// Creates a die whose first roll is 1.
+    pub fn new() -> Self {
+        Self { state: 1, rolls: 0 }
+    }
+
+    fn roll(&mut self) -> u32 {
+        let result = self.state;
+
+        self.rolls += 1;
+        self.state += 1;
+        if self.state > 100 {
+            self.state = 1;
+        }
+
+        result
+    }
+}
+
+impl Default for DeterministicDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A 3-sided die that splits the universe into one copy per possible outcome.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QuantumDie;
+
+impl QuantumDie {
+    fn three_roll_sums(&mut self) -> [u32; 27] {
+        [
+            1 + 1 + 1,
+            1 + 1 + 2,
+            1 + 1 + 3,
+            1 + 2 + 1,
+            1 + 2 + 2,
+            1 + 2 + 3,
+            1 + 3 + 1,
+            1 + 3 + 2,
+            1 + 3 + 3,
+            2 + 1 + 1,
+            2 + 1 + 2,
+            2 + 1 + 3,
+            2 + 2 + 1,
+            2 + 2 + 2,
+            2 + 2 + 3,
+            2 + 3 + 1,
+            2 + 3 + 2,
+            2 + 3 + 3,
+            3 + 1 + 1,
+            3 + 1 + 2,
+            3 + 1 + 3,
+            3 + 2 + 1,
+            3 + 2 + 2,
+            3 + 2 + 3,
+            3 + 3 + 1,
+            3 + 3 + 2,
+            3 + 3 + 3,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        find_losing_player, parse_input, simulate_deterministic, simulate_quantum,
+        DeterministicDie, Player, QuantumDie,
+    };
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok([Player::new(4), Player::new(8)]),
+            parse_input(
+                "Player 1 starting position: 4
+Player 2 starting position: 8"
+            )
+        );
+        assert_eq!(
+            "line 1, column 30: Expected two players",
+            parse_input("Player 1 starting position: 4")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 2, column 29: Positions go from 1 to 10",
+            parse_input("Player 1 starting position: 4\nPlayer 2 starting position: 11")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let mut players = [Player::new(4), Player::new(8)];
+        let mut die = DeterministicDie::new();
+
+        let winner = simulate_deterministic(&mut players, &mut die, 1000);
+        assert_eq!(0, winner);
+
+        let losing_player = find_losing_player(&players, winner).unwrap();
+        assert_eq!(739785, losing_player.score * die.rolls);
+    }
+
+    #[test]
+    fn part2() {
+        let mut players = [Player::new(4), Player::new(8)];
+
+        let winner = simulate_quantum(&mut players, &mut QuantumDie, 0, 21);
+        assert_eq!([444356092776315, 341960390180808], winner);
+    }
+}
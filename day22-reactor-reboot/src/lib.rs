@@ -0,0 +1,379 @@
+//! Day 22: Reactor Reboot.
+//!
+//! Turns cuboids of reactor cubes on and off, and counts how many cubes are on
+//! at the end.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+use regex::Regex;
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 22.
+pub struct ReactorReboot;
+
+impl Solution for ReactorReboot {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+
+    type Parsed<'a> = Vec<Step>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Vec<Step>) -> Option<i64> {
+        Some(reboot_within(steps, &INITIALIZATION_REGION))
+    }
+
+    fn part2(steps: &Vec<Step>) -> Option<i64> {
+        Some(reboot(steps))
+    }
+}
+
+/// The cubes from -50 to 50 along every axis, which the initialization
+/// procedure is limited to.
+pub const INITIALIZATION_REGION: Cuboid = Cuboid {
+    min: [-50; 3],
+    max: [50; 3],
+};
+
+/// A cuboid of cubes, from `min` to `max` (inclusive) along the x, y and z
+/// axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cuboid {
+    /// The lowest coordinate along each axis.
+    pub min: [i64; 3],
+    /// The highest coordinate along each axis.
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    /// The number of cubes inside.
+    pub fn volume(&self) -> i64 {
+        (0..3)
+            .map(|axis| self.max[axis] - self.min[axis] + 1)
+            .product()
+    }
+
+    /// The cubes inside both cuboids, if they overlap.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let mut intersection = *self;
+        for axis in 0..3 {
+            intersection.min[axis] = self.min[axis].max(other.min[axis]);
+            intersection.max[axis] = self.max[axis].min(other.max[axis]);
+
+            if intersection.min[axis] > intersection.max[axis] {
+                return None;
+            }
+        }
+
+        Some(intersection)
+    }
+}
+
+/// A single reboot step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Whether to turn the cubes on or off.
+    pub on: bool,
+    /// The cubes to turn on or off.
+    pub cuboid: Cuboid,
+}
+
+/// Parses one reboot step per line, such as
+/// `on x=-20..26,y=-36..17,z=-47..7`.
+pub fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    let regex =
+        Regex::new(r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
+            .unwrap();
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let c = regex.captures(line).ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Expected a reboot step, such as `on x=10..12,y=10..12,z=10..12`",
+                )
+            })?;
+            let number =
+                |group| aoc_core::parse::number::<i64>(index, line, c.get(group).unwrap().as_str());
+
+            let mut cuboid = Cuboid {
+                min: [0; 3],
+                max: [0; 3],
+            };
+            for axis in 0..3 {
+                cuboid.min[axis] = number(2 + axis * 2)?;
+                cuboid.max[axis] = number(3 + axis * 2)?;
+
+                if cuboid.min[axis] > cuboid.max[axis] {
+                    return Err(ParseError::at(
+                        index,
+                        line,
+                        c.get(2 + axis * 2).unwrap().as_str(),
+                        "Ranges have to go from low to high",
+                    ));
+                }
+            }
+
+            Ok(Step {
+                on: &c[1] == "on",
+                cuboid,
+            })
+        })
+        .collect()
+}
+
+/// Runs every step, and returns the number of cubes that are on at the end.
+///
+/// Instead of storing cubes, this keeps a list of signed cuboids whose volumes
+/// add up to the number of cubes that are on. Every step cancels out the parts
+/// of the cuboids so far that it overlaps, by adding their intersections with
+/// the opposite sign, and then adds its own cuboid if it turns cubes on.
+pub fn reboot(steps: &[Step]) -> i64 {
+    let mut cuboids: Vec<(Cuboid, i64)> = vec![];
+
+    for step in steps {
+        let overlaps: Vec<_> = cuboids
+            .iter()
+            .filter_map(|(cuboid, sign)| {
+                cuboid
+                    .intersection(&step.cuboid)
+                    .map(|intersection| (intersection, -sign))
+            })
+            .collect();
+        cuboids.extend(overlaps);
+
+        if step.on {
+            cuboids.push((step.cuboid, 1));
+        }
+    }
+
+    cuboids
+        .iter()
+        .map(|(cuboid, sign)| cuboid.volume() * sign)
+        .sum()
+}
+
+/// Runs every step, but only for the cubes inside `region`.
+pub fn reboot_within(steps: &[Step], region: &Cuboid) -> i64 {
+    let clipped: Vec<_> = steps
+        .iter()
+        .filter_map(|step| {
+            step.cuboid
+                .intersection(region)
+                .map(|cuboid| Step { cuboid, ..*step })
+        })
+        .collect();
+
+    reboot(&clipped)
+}
+
+/// Runs every step inside the initialization region, turning each cube on or
+/// off individually. Only meant for cross-checking [`reboot_within`].
+pub fn reboot_naive(steps: &[Step]) -> usize {
+    const SIZE: usize = 101;
+    let mut cubes = vec![0u64; (SIZE * SIZE * SIZE).div_ceil(64)];
+
+    for step in steps {
+        let Some(cuboid) = step.cuboid.intersection(&INITIALIZATION_REGION) else {
+            continue;
+        };
+
+        for x in cuboid.min[0]..=cuboid.max[0] {
+            for y in cuboid.min[1]..=cuboid.max[1] {
+                for z in cuboid.min[2]..=cuboid.max[2] {
+                    let [x, y, z] = [x, y, z].map(|coordinate| (coordinate + 50) as usize);
+                    let bit = (x * SIZE + y) * SIZE + z;
+
+                    if step.on {
+                        cubes[bit / 64] |= 1 << (bit % 64);
+                    } else {
+                        cubes[bit / 64] &= !(1 << (bit % 64));
+                    }
+                }
+            }
+        }
+    }
+
+    cubes.iter().map(|bits| bits.count_ones() as usize).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_input, reboot, reboot_naive, reboot_within, Cuboid, Step, INITIALIZATION_REGION,
+    };
+
+    const SMALL_EXAMPLE: &str = "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    const LARGER_EXAMPLE: &str = "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682";
+
+    const PART2_EXAMPLE: &str = "on x=-5..47,y=-31..22,z=-19..33
+on x=-44..5,y=-27..21,z=-14..35
+on x=-49..-1,y=-11..42,z=-10..38
+on x=-20..34,y=-40..6,z=-44..1
+off x=26..39,y=40..50,z=-2..11
+on x=-41..5,y=-41..6,z=-36..8
+off x=-43..-33,y=-45..-28,z=7..25
+on x=-33..15,y=-32..19,z=-34..11
+off x=35..47,y=-46..-34,z=-11..5
+on x=-14..36,y=-6..44,z=-16..29
+on x=-57795..-6158,y=29564..72030,z=20435..90618
+on x=36731..105352,y=-21140..28532,z=16094..90401
+on x=30999..107136,y=-53464..15513,z=8553..71215
+on x=13528..83982,y=-99403..-27377,z=-24141..23996
+on x=-72682..-12347,y=18159..111354,z=7391..80950
+on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+on x=-52752..22273,y=-49450..9096,z=54442..119054
+on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+on x=-4958..62750,y=40422..118853,z=-7672..65583
+on x=55694..108686,y=-43367..46958,z=-26781..48729
+on x=-98497..-18186,y=-63569..3412,z=1232..88485
+on x=-726..56291,y=-62629..13224,z=18033..85226
+on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+on x=-65152..-11147,y=22489..91432,z=-58782..1780
+on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+on x=-57817..18248,y=49321..117703,z=5745..55881
+on x=14781..98692,y=-1341..70827,z=15753..70151
+on x=-34419..55919,y=-19626..40991,z=39015..114138
+on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+on x=-32178..58085,y=17647..101866,z=-91405..-8878
+on x=-53655..12091,y=50097..105568,z=-75335..-4862
+on x=-111166..-40997,y=-71714..2688,z=5609..50954
+on x=-16602..70118,y=-98693..-44401,z=5197..76897
+on x=16383..101554,y=4615..83635,z=-44907..18747
+off x=-95822..-15171,y=-19987..48940,z=10804..104439
+on x=-89813..-14614,y=16069..88491,z=-3297..45228
+on x=41075..99376,y=-20427..49978,z=-52012..13762
+on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+on x=-16478..35915,y=36008..118594,z=-7885..47086
+off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+off x=2032..69770,y=-71013..4824,z=7471..94418
+on x=43670..120875,y=-42068..12382,z=-24787..38892
+off x=37514..111226,y=-45862..25743,z=-16714..54663
+off x=25699..97951,y=-30668..59918,z=-15349..69697
+off x=-44271..17935,y=-9516..60759,z=49131..112598
+on x=-61695..-5813,y=40978..94975,z=8655..80240
+off x=-101086..-9439,y=-7088..67543,z=33935..83858
+off x=18020..114017,y=-48931..32606,z=21474..89843
+off x=-77139..10506,y=-89994..-18797,z=-80..59318
+off x=8476..79288,y=-75520..11602,z=-96624..-24783
+on x=-47488..-1262,y=24338..100707,z=16292..72967
+off x=-84341..13987,y=2429..92914,z=-90671..-1318
+off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+off x=-27365..46395,y=31009..98017,z=15428..76570
+off x=-70369..-16548,y=22648..78696,z=-1892..86821
+on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(vec![Step {
+                on: false,
+                cuboid: Cuboid {
+                    min: [-54112, -85059, -27449],
+                    max: [-39298, -49293, 7877],
+                },
+            }]),
+            parse_input("off x=-54112..-39298,y=-85059..-49293,z=-27449..7877")
+        );
+        assert_eq!(
+            "line 2, column 1: Expected a reboot step, such as `on x=10..12,y=10..12,z=10..12`",
+            parse_input("on x=1..2,y=1..2,z=1..2\ntoggle x=1..2,y=1..2,z=1..2")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 1, column 13: Ranges have to go from low to high",
+            parse_input("on x=1..2,y=5..3,z=1..2")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Cuboid {
+            min: [0, 0, 0],
+            max: [9, 9, 9],
+        };
+        let b = Cuboid {
+            min: [5, -5, 9],
+            max: [15, 5, 20],
+        };
+
+        assert_eq!(1000, a.volume());
+        assert_eq!(
+            Some(Cuboid {
+                min: [5, 0, 9],
+                max: [9, 5, 9],
+            }),
+            a.intersection(&b)
+        );
+        assert_eq!(
+            None,
+            a.intersection(&Cuboid {
+                min: [10, 0, 0],
+                max: [10, 9, 9],
+            })
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let steps = parse_input(SMALL_EXAMPLE).unwrap();
+        assert_eq!(39, reboot_naive(&steps));
+        assert_eq!(39, reboot_within(&steps, &INITIALIZATION_REGION));
+        assert_eq!(39, reboot(&steps));
+
+        // Some of the steps reach outside of the initialization region
+        let steps = parse_input(LARGER_EXAMPLE).unwrap();
+        assert_eq!(590784, reboot_naive(&steps));
+        assert_eq!(590784, reboot_within(&steps, &INITIALIZATION_REGION));
+    }
+
+    #[test]
+    fn part2() {
+        let steps = parse_input(PART2_EXAMPLE).unwrap();
+        assert_eq!(474140, reboot_within(&steps, &INITIALIZATION_REGION));
+        assert_eq!(2758514936282235, reboot(&steps));
+    }
+}
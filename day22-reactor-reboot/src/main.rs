@@ -0,0 +1,5 @@
+use day22_reactor_reboot::{ReactorReboot, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<ReactorReboot>(BUNDLED_INPUT);
+}
@@ -0,0 +1,537 @@
+//! Day 23: Amphipod.
+//!
+//! Finds the cheapest way to move amphipods through a burrow until each
+//! species sits in its own side room.
+
+#![warn(missing_docs)]
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::{self, Display, Write},
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 23.
+pub struct Amphipod;
+
+impl Solution for Amphipod {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Amphipod";
+
+    type Parsed<'a> = Burrow;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Burrow, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(burrow: &Burrow) -> Option<u32> {
+        organize(burrow).map(|plan| plan.energy)
+    }
+
+    fn part2(burrow: &Burrow) -> Option<u32> {
+        organize(&burrow.unfold()?).map(|plan| plan.energy)
+    }
+}
+
+/// The number of spaces in the hallway.
+const HALLWAY_LENGTH: usize = 11;
+
+/// The hallway position right outside of `room`. Amphipods never stop there.
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// The four species of amphipods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Species {
+    /// `A`, which lives in the first room.
+    Amber,
+    /// `B`, which lives in the second room.
+    Bronze,
+    /// `C`, which lives in the third room.
+    Copper,
+    /// `D`, which lives in the fourth room.
+    Desert,
+}
+
+impl Species {
+    const ALL: [Species; 4] = [
+        Species::Amber,
+        Species::Bronze,
+        Species::Copper,
+        Species::Desert,
+    ];
+
+    /// The energy it takes to move one step.
+    pub fn energy(self) -> u32 {
+        match self {
+            Species::Amber => 1,
+            Species::Bronze => 10,
+            Species::Copper => 100,
+            Species::Desert => 1000,
+        }
+    }
+
+    /// The index of the room this species has to end up in.
+    pub fn room(self) -> usize {
+        self as usize
+    }
+
+    /// The letter used in the diagram of the burrow.
+    pub fn letter(self) -> char {
+        (b'A' + self as u8) as char
+    }
+
+    fn from_letter(c: char) -> Option<Species> {
+        Self::ALL.into_iter().find(|species| species.letter() == c)
+    }
+}
+
+/// A space an amphipod can be in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slot {
+    /// A position in the hallway, counting from 0 on the left.
+    Hallway(usize),
+    /// A room, and the position inside it, counting from 0 at the top.
+    Room(usize, usize),
+}
+
+impl Display for Slot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Slot::Hallway(position) => write!(f, "hallway {}", position),
+            Slot::Room(room, depth) => write!(
+                f,
+                "room {} (depth {})",
+                Species::ALL[*room].letter(),
+                depth + 1
+            ),
+        }
+    }
+}
+
+/// A single amphipod moving from one slot to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    /// The amphipod that moves.
+    pub species: Species,
+    /// Where it starts.
+    pub from: Slot,
+    /// Where it stops.
+    pub to: Slot,
+    /// The energy the move takes.
+    pub energy: u32,
+}
+
+/// The hallway and the four side rooms, and which amphipods are where.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Burrow {
+    hallway: [Option<Species>; HALLWAY_LENGTH],
+    /// Each room from the top down. All rooms are equally deep.
+    rooms: [Vec<Option<Species>>; 4],
+}
+
+impl Burrow {
+    /// How many amphipods fit in each room.
+    pub fn depth(&self) -> usize {
+        self.rooms[0].len()
+    }
+
+    /// Whether every room is full of the species it belongs to.
+    pub fn is_organized(&self) -> bool {
+        self.rooms.iter().enumerate().all(|(room, slots)| {
+            slots
+                .iter()
+                .all(|slot| slot.map(Species::room) == Some(room))
+        })
+    }
+
+    /// Inserts the two rows from the folded part of the diagram between the
+    /// first and second row of each room, making them 2 deeper.
+    ///
+    /// ## Returns
+    ///
+    /// The unfolded burrow, or `None` if the rooms aren't 2 deep, as only the
+    /// diagram from part 1 is folded.
+    pub fn unfold(&self) -> Option<Burrow> {
+        if self.depth() != 2 {
+            return None;
+        }
+
+        let mut unfolded = self.clone();
+        for (room, inserted) in unfolded.rooms.iter_mut().zip([
+            [Species::Desert, Species::Desert],
+            [Species::Copper, Species::Bronze],
+            [Species::Bronze, Species::Amber],
+            [Species::Amber, Species::Copper],
+        ]) {
+            room.splice(1..1, inserted.map(Some));
+        }
+
+        Some(unfolded)
+    }
+
+    /// Whether nothing is in the way between hallway positions `from` and
+    /// `to`, including `to` but not `from`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to {
+            &self.hallway[from + 1..=to]
+        } else {
+            &self.hallway[to..from]
+        };
+
+        between.iter().all(Option::is_none)
+    }
+
+    /// Every move allowed from here. Amphipods only move from a room into the
+    /// hallway, or from the hallway into their own room, once it only has their
+    /// own species in it.
+    pub fn moves(&self) -> Vec<Move> {
+        let mut moves = vec![];
+
+        for (position, species) in self
+            .hallway
+            .iter()
+            .enumerate()
+            .filter_map(|(position, slot)| slot.map(|species| (position, species)))
+        {
+            let room = species.room();
+            let has_others = self.rooms[room]
+                .iter()
+                .any(|slot| slot.is_some_and(|other| other != species));
+            if has_others || !self.is_clear(position, door(room)) {
+                continue;
+            }
+
+            if let Some(depth) = self.rooms[room].iter().rposition(Option::is_none) {
+                let steps = position.abs_diff(door(room)) + depth + 1;
+                moves.push(Move {
+                    species,
+                    from: Slot::Hallway(position),
+                    to: Slot::Room(room, depth),
+                    energy: steps as u32 * species.energy(),
+                });
+            }
+        }
+
+        for (room, slots) in self.rooms.iter().enumerate() {
+            let Some(depth) = slots.iter().position(Option::is_some) else {
+                continue;
+            };
+            // Amphipods that are already home, with nobody below them who has
+            // to leave, stay put
+            if slots[depth..]
+                .iter()
+                .all(|slot| slot.map(Species::room) == Some(room))
+            {
+                continue;
+            }
+
+            let species = slots[depth].unwrap();
+            for position in (0..HALLWAY_LENGTH).filter(|&position| {
+                !(0..4).any(|room| door(room) == position) && self.is_clear(door(room), position)
+            }) {
+                let steps = depth + 1 + door(room).abs_diff(position);
+                moves.push(Move {
+                    species,
+                    from: Slot::Room(room, depth),
+                    to: Slot::Hallway(position),
+                    energy: steps as u32 * species.energy(),
+                });
+            }
+        }
+
+        moves
+    }
+
+    fn slot_mut(&mut self, slot: Slot) -> &mut Option<Species> {
+        match slot {
+            Slot::Hallway(position) => &mut self.hallway[position],
+            Slot::Room(room, depth) => &mut self.rooms[room][depth],
+        }
+    }
+
+    /// The burrow after making `step`, which should be one of [`Burrow::moves`].
+    pub fn apply(&self, step: &Move) -> Burrow {
+        let mut burrow = self.clone();
+        let species = burrow.slot_mut(step.from).take();
+        *burrow.slot_mut(step.to) = species;
+
+        burrow
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = |slot: &Option<Species>| slot.map_or('.', Species::letter);
+
+        writeln!(f, "#############")?;
+        writeln!(
+            f,
+            "#{}#",
+            self.hallway.iter().map(letter).collect::<String>()
+        )?;
+        for depth in 0..self.depth() {
+            let (start, end) = if depth == 0 {
+                ("###", "###")
+            } else {
+                ("  #", "#")
+            };
+            let row: Vec<_> = self
+                .rooms
+                .iter()
+                .map(|room| letter(&room[depth]).to_string())
+                .collect();
+            writeln!(f, "{}{}{}", start, row.join("#"), end)?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+/// The cheapest way to organize the burrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plan {
+    /// The total energy of all moves.
+    pub energy: u32,
+    /// Every move, in order.
+    pub moves: Vec<Move>,
+}
+
+/// Parses a diagram of the burrow. The hallway has to be empty, and every room
+/// full.
+///
+/// ```text
+/// #############
+/// #...........#
+/// ###B#C#B#D###
+///   #A#D#C#A#
+///   #########
+/// ```
+pub fn parse_input(input: &str) -> Result<Burrow, ParseError> {
+    let lines: Vec<_> = input.lines().collect();
+    if lines.get(1).map(|line| line.trim_end()) != Some("#...........#") {
+        return Err(ParseError::new(
+            2,
+            1,
+            "Expected an empty hallway, such as `#...........#`",
+        ));
+    }
+
+    let mut rooms: [Vec<Option<Species>>; 4] = Default::default();
+    for (index, line) in lines.iter().enumerate().skip(2) {
+        if line.trim().chars().all(|c| c == '#') {
+            break;
+        }
+
+        for (room, slots) in rooms.iter_mut().enumerate() {
+            let column = door(room) + 1;
+            match line.chars().nth(column) {
+                Some(c) if Species::from_letter(c).is_some() => {
+                    slots.push(Species::from_letter(c));
+                }
+                Some(c) => {
+                    return Err(ParseError::new(
+                        index + 1,
+                        column + 1,
+                        format!("Expected an amphipod (A, B, C or D), found `{}`", c),
+                    ))
+                }
+                None => {
+                    return Err(ParseError::new(
+                        index + 1,
+                        line.chars().count() + 1,
+                        "Expected an amphipod (A, B, C or D)",
+                    ))
+                }
+            }
+        }
+    }
+
+    if rooms[0].is_empty() {
+        return Err(ParseError::end_of_input(input, "Expected the side rooms"));
+    }
+
+    Ok(Burrow {
+        hallway: [None; HALLWAY_LENGTH],
+        rooms,
+    })
+}
+
+/// Finds the cheapest sequence of moves that organizes the burrow, with
+/// Dijkstra's algorithm over every reachable state of the burrow.
+///
+/// Like day 21 memoizes the outcome of each game state in a `HashMap` keyed on
+/// the state itself, this keeps the lowest energy found so far for each
+/// burrow, and the move that reached it.
+///
+/// ## Returns
+///
+/// The plan, or `None` if the burrow can't be organized.
+pub fn organize(burrow: &Burrow) -> Option<Plan> {
+    let mut lowest_energy = HashMap::from([(burrow.clone(), 0)]);
+    let mut came_from: HashMap<Burrow, (Burrow, Move)> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((0, burrow.clone()))]);
+
+    while let Some(Reverse((energy, current))) = queue.pop() {
+        if current.is_organized() {
+            let mut moves = vec![];
+            let mut state = &current;
+            while let Some((previous, step)) = came_from.get(state) {
+                moves.push(*step);
+                state = previous;
+            }
+            moves.reverse();
+
+            return Some(Plan { energy, moves });
+        }
+        // Already reached with less energy through other moves
+        if energy > lowest_energy[&current] {
+            continue;
+        }
+
+        for step in current.moves() {
+            let next = current.apply(&step);
+            let next_energy = energy + step.energy;
+            if lowest_energy
+                .get(&next)
+                .is_none_or(|&lowest| next_energy < lowest)
+            {
+                lowest_energy.insert(next.clone(), next_energy);
+                came_from.insert(next.clone(), (current.clone(), step));
+                queue.push(Reverse((next_energy, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Describes every move of the plan, each followed by the burrow after it.
+pub fn describe(burrow: &Burrow, plan: &Plan) -> String {
+    let mut result = format!("{}\n", burrow);
+    let mut burrow = burrow.clone();
+
+    for step in &plan.moves {
+        burrow = burrow.apply(step);
+        write!(
+            result,
+            "\n{} moves from {} to {}, using {} energy\n{}\n",
+            step.species.letter(),
+            step.from,
+            step.to,
+            step.energy,
+            burrow
+        )
+        .unwrap();
+    }
+    write!(result, "\nTotal energy: {}", plan.energy).unwrap();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use crate::{describe, organize, parse_input, Amphipod, Move, Slot, Species};
+
+    const TEST_INPUT: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn parse() {
+        let burrow = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(2, burrow.depth());
+        assert_eq!(TEST_INPUT, burrow.to_string());
+
+        assert_eq!(
+            "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########",
+            burrow.unfold().unwrap().to_string()
+        );
+
+        assert_eq!(
+            "line 3, column 6: Expected an amphipod (A, B, C or D), found `E`",
+            parse_input(&TEST_INPUT.replace("C#B#D", "E#B#D"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 2, column 1: Expected an empty hallway, such as `#...........#`",
+            parse_input("#############\n#A..........#")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn moves() {
+        let burrow = parse_input(TEST_INPUT).unwrap();
+        let moves = burrow.moves();
+
+        // The top amphipod of each room can go to each of the 7 hallway spots
+        assert_eq!(28, moves.len());
+        assert!(moves.contains(&Move {
+            species: Species::Bronze,
+            from: Slot::Room(2, 0),
+            to: Slot::Hallway(3),
+            energy: 40,
+        }));
+    }
+
+    #[test]
+    fn part1() {
+        let burrow = parse_input(TEST_INPUT).unwrap();
+        let plan = organize(&burrow).unwrap();
+        assert_eq!(12521, plan.energy);
+
+        let organized = plan
+            .moves
+            .iter()
+            .fold(burrow.clone(), |burrow, step| burrow.apply(step));
+        assert!(organized.is_organized());
+        assert_eq!(
+            plan.energy,
+            plan.moves.iter().map(|step| step.energy).sum::<u32>()
+        );
+
+        let description = describe(&burrow, &plan);
+        assert!(description.ends_with("  #########\n\nTotal energy: 12521"));
+        assert!(description.contains("#...........#\n###A#B#C#D###\n  #A#B#C#D#"));
+    }
+
+    #[test]
+    fn unfold_depth() {
+        let unfolded = parse_input(TEST_INPUT).unwrap().unfold().unwrap();
+        assert_eq!(4, unfolded.depth());
+        assert_eq!(None, unfolded.unfold());
+
+        let shallow =
+            parse_input("#############\n#...........#\n###B#A#D#C###\n  #########").unwrap();
+        assert_eq!(1, shallow.depth());
+        assert_eq!(None, shallow.unfold());
+        assert_eq!(None, Amphipod::part2(&shallow));
+    }
+
+    #[test]
+    fn part2() {
+        let burrow = parse_input(TEST_INPUT).unwrap().unfold().unwrap();
+
+        assert_eq!(Some(44169), organize(&burrow).map(|plan| plan.energy));
+    }
+}
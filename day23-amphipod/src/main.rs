@@ -0,0 +1,5 @@
+use day23_amphipod::{Amphipod, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<Amphipod>(BUNDLED_INPUT);
+}
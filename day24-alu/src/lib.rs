@@ -0,0 +1,632 @@
+//! Day 24: Arithmetic Logic Unit.
+//!
+//! Runs programs on the submarine's ALU, and works out which model numbers the
+//! MONAD program accepts by analyzing its structure.
+
+#![warn(missing_docs)]
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Write},
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 24.
+pub struct ArithmeticLogicUnit;
+
+impl Solution for ArithmeticLogicUnit {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+
+    type Parsed<'a> = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> Option<u64> {
+        Some(largest_model_number(&constraints(&analyze(program)?)?))
+    }
+
+    fn part2(program: &Vec<Instruction>) -> Option<u64> {
+        Some(smallest_model_number(&constraints(&analyze(program)?)?))
+    }
+}
+
+/// One of the ALU's four registers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    /// `w`
+    W,
+    /// `x`
+    X,
+    /// `y`
+    Y,
+    /// `z`
+    Z,
+}
+
+impl Register {
+    const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+    /// The register's name, e.g. `w`.
+    pub fn name(self) -> char {
+        ['w', 'x', 'y', 'z'][self as usize]
+    }
+}
+
+/// The second argument of an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    /// The value of a register.
+    Register(Register),
+    /// A number.
+    Number(i64),
+}
+
+/// A single ALU instruction. The result is always stored in the first
+/// register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// Reads the next input digit.
+    Inp(Register),
+    /// Adds both values.
+    Add(Register, Operand),
+    /// Multiplies both values.
+    Mul(Register, Operand),
+    /// Divides, rounding towards zero.
+    Div(Register, Operand),
+    /// Takes the remainder of dividing the values.
+    Mod(Register, Operand),
+    /// 1 if both values are equal, 0 otherwise.
+    Eql(Register, Operand),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, register, operand) = match self {
+            Instruction::Inp(register) => return write!(f, "inp {}", register.name()),
+            Instruction::Add(register, operand) => ("add", register, operand),
+            Instruction::Mul(register, operand) => ("mul", register, operand),
+            Instruction::Div(register, operand) => ("div", register, operand),
+            Instruction::Mod(register, operand) => ("mod", register, operand),
+            Instruction::Eql(register, operand) => ("eql", register, operand),
+        };
+
+        match operand {
+            Operand::Register(operand) => {
+                write!(f, "{} {} {}", name, register.name(), operand.name())
+            }
+            Operand::Number(number) => write!(f, "{} {} {}", name, register.name(), number),
+        }
+    }
+}
+
+/// Parses the program, one instruction per line.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_instruction(line).map_err(|e| e.on_line(index)))
+        .collect()
+}
+
+/// Parses an instruction such as `add x -1`. Errors point at line 1.
+pub fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let mut parts = line.split(' ');
+    let name = parts.next().unwrap_or_default();
+
+    let register = |part: Option<&str>| match part {
+        Some(part @ ("w" | "x" | "y" | "z")) => {
+            Ok(Register::ALL[part.as_bytes()[0] as usize - b'w' as usize])
+        }
+        Some(part) => Err(ParseError::at(
+            0,
+            line,
+            part,
+            format!("Expected a register, found `{}`", part),
+        )),
+        None => Err(ParseError::end_of_input(line, "Expected a register")),
+    };
+    let first = register(parts.next())?;
+    if name == "inp" {
+        return match parts.next() {
+            Some(part) => Err(ParseError::at(0, line, part, "`inp` only takes a register")),
+            None => Ok(Instruction::Inp(first)),
+        };
+    }
+
+    let operand = match parts.next() {
+        Some(part) if part.starts_with(|c: char| c.is_ascii_lowercase()) => {
+            Operand::Register(register(Some(part))?)
+        }
+        Some(part) => Operand::Number(aoc_core::parse::number(0, line, part)?),
+        None => return Err(ParseError::end_of_input(line, "Expected a second argument")),
+    };
+    if let Some(part) = parts.next() {
+        return Err(ParseError::at(0, line, part, "Unexpected third argument"));
+    }
+
+    match name {
+        "add" => Ok(Instruction::Add(first, operand)),
+        "mul" => Ok(Instruction::Mul(first, operand)),
+        "div" => Ok(Instruction::Div(first, operand)),
+        "mod" => Ok(Instruction::Mod(first, operand)),
+        "eql" => Ok(Instruction::Eql(first, operand)),
+        _ => Err(ParseError::at(
+            0,
+            line,
+            name,
+            format!("Unknown instruction `{}`", name),
+        )),
+    }
+}
+
+/// Why the ALU stopped before the end of the program. Each holds the index of
+/// the instruction it stopped at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AluError {
+    /// `inp` ran out of input.
+    MissingInput(usize),
+    /// `div` by 0.
+    DivisionByZero(usize),
+    /// `mod` of a negative number, or by a number that is not positive.
+    InvalidModulo(usize),
+    /// The result doesn't fit in 64 bits.
+    Overflow(usize),
+}
+
+impl Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AluError::MissingInput(index) => write!(f, "instruction {}: no input left", index + 1),
+            AluError::DivisionByZero(index) => {
+                write!(f, "instruction {}: division by zero", index + 1)
+            }
+            AluError::InvalidModulo(index) => {
+                write!(
+                    f,
+                    "instruction {}: modulo of a negative number, or by a number below 1",
+                    index + 1
+                )
+            }
+            AluError::Overflow(index) => write!(f, "instruction {}: overflow", index + 1),
+        }
+    }
+}
+
+impl Error for AluError {}
+
+/// The values of `w`, `x`, `y` and `z`.
+pub type Registers = [i64; 4];
+
+/// Runs the program with the given inputs, starting with every register at 0,
+/// and calls `on_step` with the index of every instruction and the registers
+/// after running it.
+pub fn execute(
+    program: &[Instruction],
+    inputs: &[i64],
+    mut on_step: impl FnMut(usize, &Registers),
+) -> Result<Registers, AluError> {
+    let mut registers = [0; 4];
+    let mut inputs = inputs.iter();
+
+    for (index, instruction) in program.iter().enumerate() {
+        let value = |registers: &Registers, operand: &Operand| match operand {
+            Operand::Register(register) => registers[*register as usize],
+            Operand::Number(number) => *number,
+        };
+
+        match instruction {
+            Instruction::Inp(register) => {
+                registers[*register as usize] =
+                    *inputs.next().ok_or(AluError::MissingInput(index))?;
+            }
+            Instruction::Add(register, operand) => {
+                registers[*register as usize] = registers[*register as usize]
+                    .checked_add(value(&registers, operand))
+                    .ok_or(AluError::Overflow(index))?;
+            }
+            Instruction::Mul(register, operand) => {
+                registers[*register as usize] = registers[*register as usize]
+                    .checked_mul(value(&registers, operand))
+                    .ok_or(AluError::Overflow(index))?;
+            }
+            Instruction::Div(register, operand) => {
+                let divisor = value(&registers, operand);
+                if divisor == 0 {
+                    return Err(AluError::DivisionByZero(index));
+                }
+                // Only the lowest number divided by -1 overflows
+                registers[*register as usize] = registers[*register as usize]
+                    .checked_div(divisor)
+                    .ok_or(AluError::Overflow(index))?;
+            }
+            Instruction::Mod(register, operand) => {
+                let divisor = value(&registers, operand);
+                if registers[*register as usize] < 0 || divisor <= 0 {
+                    return Err(AluError::InvalidModulo(index));
+                }
+                registers[*register as usize] %= divisor;
+            }
+            Instruction::Eql(register, operand) => {
+                let equal = registers[*register as usize] == value(&registers, operand);
+                registers[*register as usize] = equal as i64;
+            }
+        }
+
+        on_step(index, &registers);
+    }
+
+    Ok(registers)
+}
+
+/// Runs the program with the given inputs, and returns the final registers.
+pub fn run(program: &[Instruction], inputs: &[i64]) -> Result<Registers, AluError> {
+    execute(program, inputs, |_, _| {})
+}
+
+/// Splits a model number into its digits, which are the inputs for MONAD.
+pub fn digits(model_number: u64) -> Vec<i64> {
+    model_number
+        .to_string()
+        .bytes()
+        .map(|digit| (digit - b'0') as i64)
+        .collect()
+}
+
+/// Whether MONAD accepts the model number: it contains no zeros, and `z` is 0
+/// at the end.
+pub fn is_valid(program: &[Instruction], model_number: u64) -> bool {
+    let digits = digits(model_number);
+
+    !digits.contains(&0) && run(program, &digits).is_ok_and(|registers| registers[3] == 0)
+}
+
+/// Runs a model number through the program, and lists every instruction with
+/// the registers after it, one per line.
+pub fn trace(program: &[Instruction], model_number: u64) -> Result<String, AluError> {
+    let mut result = String::new();
+    execute(program, &digits(model_number), |index, registers| {
+        writeln!(
+            result,
+            "{:>4}  {:<10}  w={} x={} y={} z={}",
+            index + 1,
+            program[index].to_string(),
+            registers[0],
+            registers[1],
+            registers[2],
+            registers[3]
+        )
+        .unwrap();
+    })?;
+
+    Ok(result)
+}
+
+/// The number of instructions MONAD uses per digit.
+const BLOCK_LENGTH: usize = 18;
+
+/// The three numbers that differ between MONAD's blocks, one block per digit.
+///
+/// Each block treats `z` as a stack of base 26 digits. It compares the digit
+/// with the top of the stack plus `check`, optionally popping the top first,
+/// and if they differ pushes the digit plus `offset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Block {
+    /// Whether the block pops the top of the stack (`div z 26`), rather than
+    /// leaving it (`div z 1`).
+    pub pops: bool,
+    /// Added to the top of the stack before comparing it with the digit.
+    pub check: i64,
+    /// Added to the digit before it is pushed.
+    pub offset: i64,
+}
+
+/// Picks the numbers out of each of MONAD's blocks.
+///
+/// ## Returns
+///
+/// The blocks, or `None` if the program doesn't follow MONAD's structure.
+pub fn analyze(program: &[Instruction]) -> Option<Vec<Block>> {
+    use Instruction::*;
+    use Operand::Number;
+    use Register::*;
+
+    if program.is_empty() || !program.len().is_multiple_of(BLOCK_LENGTH) {
+        return None;
+    }
+
+    program
+        .chunks(BLOCK_LENGTH)
+        .map(|block| match (block[0], block[4], block[5], block[15]) {
+            (
+                Inp(W),
+                Div(Z, Number(divisor @ (1 | 26))),
+                Add(X, Number(check)),
+                Add(Y, Number(offset)),
+            ) => Some(Block {
+                pops: divisor == 26,
+                check,
+                offset,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A pair of digits that MONAD ties together: the digit at `second` has to
+/// equal the digit at `first` plus `difference`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    /// The index of the digit pushed onto the stack.
+    pub first: usize,
+    /// The index of the digit that pops it.
+    pub second: usize,
+    /// How much larger the second digit has to be.
+    pub difference: i64,
+}
+
+/// Pairs up the blocks that push with those that pop. For `z` to end up at 0,
+/// every popping block must match the digit pushed, or it pushes instead.
+///
+/// ## Returns
+///
+/// The constraints, or `None` if the pushes and pops don't pair up, a block
+/// that doesn't pop can match the digit (which MONAD never does), or two paired
+/// digits would have to differ by more than 8.
+pub fn constraints(blocks: &[Block]) -> Option<Vec<Constraint>> {
+    let mut stack = vec![];
+    let mut constraints = vec![];
+
+    for (index, block) in blocks.iter().enumerate() {
+        if block.pops {
+            let (first, offset) = stack.pop()?;
+            let difference: i64 = offset + block.check;
+            if difference.abs() > 8 {
+                return None;
+            }
+
+            constraints.push(Constraint {
+                first,
+                second: index,
+                difference,
+            });
+        } else if block.check <= 9 {
+            return None;
+        } else {
+            stack.push((index, block.offset));
+        }
+    }
+
+    stack.is_empty().then_some(constraints)
+}
+
+/// Picks each digit of the model number, given a constraint and whether the
+/// number should be as large as possible.
+fn model_number(constraints: &[Constraint], largest: bool) -> u64 {
+    let mut digits = vec![0; constraints.len() * 2];
+
+    for constraint in constraints {
+        let (first, second) = match (largest, constraint.difference >= 0) {
+            (true, true) => (9 - constraint.difference, 9),
+            (true, false) => (9, 9 + constraint.difference),
+            (false, true) => (1, 1 + constraint.difference),
+            (false, false) => (1 - constraint.difference, 1),
+        };
+
+        digits[constraint.first] = first;
+        digits[constraint.second] = second;
+    }
+
+    digits
+        .iter()
+        .fold(0, |number, &digit| number * 10 + digit as u64)
+}
+
+/// The largest model number that satisfies every constraint.
+pub fn largest_model_number(constraints: &[Constraint]) -> u64 {
+    model_number(constraints, true)
+}
+
+/// The smallest model number that satisfies every constraint.
+pub fn smallest_model_number(constraints: &[Constraint]) -> u64 {
+    model_number(constraints, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analyze, constraints, is_valid, largest_model_number, parse_input, run,
+        smallest_model_number, trace, AluError, Block, Constraint,
+    };
+
+    /// Generates a MONAD-like program from the numbers of each block.
+    fn monad(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .map(|(divisor, check, offset)| {
+                format!(
+                    "inp w
+mul x 0
+add x z
+mod x 26
+div z {}
+add x {}
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y {}
+mul y x
+add z y",
+                    divisor, check, offset
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    const BLOCKS: [(i64, i64, i64); 14] = [
+        (1, 12, 7),
+        (1, 11, 15),
+        (1, 12, 2),
+        (26, -3, 15),
+        (1, 10, 14),
+        (26, -9, 2),
+        (1, 10, 15),
+        (26, -7, 1),
+        (26, -11, 15),
+        (26, -4, 15),
+        (1, 14, 12),
+        (1, 11, 2),
+        (26, -8, 13),
+        (26, -10, 13),
+    ];
+
+    #[test]
+    fn parse() {
+        let program = parse_input("inp w\nadd x -1\nmul y x").unwrap();
+        assert_eq!(
+            vec!["inp w", "add x -1", "mul y x"],
+            program.iter().map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            "line 2, column 1: Unknown instruction `sub`",
+            parse_input("inp w\nsub x 1").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 5: Expected a register, found `a`",
+            parse_input("add a 1").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 7: Expected a number, found `1.5`",
+            parse_input("mul x 1.5").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 6: Expected a second argument",
+            parse_input("eql x").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn interpreter() {
+        let negate = parse_input("inp x\nmul x -1").unwrap();
+        assert_eq!(Ok([0, -7, 0, 0]), run(&negate, &[7]));
+
+        let three_times = parse_input("inp z\ninp x\nmul z 3\neql z x").unwrap();
+        assert_eq!(1, run(&three_times, &[2, 6]).unwrap()[3]);
+        assert_eq!(0, run(&three_times, &[2, 7]).unwrap()[3]);
+
+        let binary = parse_input(
+            "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2",
+        )
+        .unwrap();
+        assert_eq!(Ok([1, 0, 1, 1]), run(&binary, &[11]));
+
+        assert_eq!(Err(AluError::MissingInput(1)), run(&three_times, &[2]));
+        assert_eq!(
+            Err(AluError::DivisionByZero(1)),
+            run(&parse_input("inp w\ndiv w x").unwrap(), &[1])
+        );
+        assert_eq!(
+            Err(AluError::InvalidModulo(1)),
+            run(&parse_input("inp w\nmod w 2").unwrap(), &[-1])
+        );
+        assert_eq!(
+            "instruction 2: modulo of a negative number, or by a number below 1",
+            run(&parse_input("inp w\nmod w 0").unwrap(), &[1])
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            Err(AluError::Overflow(5)),
+            run(
+                &parse_input("inp z\nmul z z\nmul z z\nmul z z\nmul z z\nmul z z\nmul z z")
+                    .unwrap(),
+                &[9]
+            )
+        );
+    }
+
+    #[test]
+    fn trace_registers() {
+        let program = parse_input("inp w\nadd z w\nmul z 3").unwrap();
+
+        assert_eq!(
+            "   1  inp w       w=4 x=0 y=0 z=0
+   2  add z w     w=4 x=0 y=0 z=4
+   3  mul z 3     w=4 x=0 y=0 z=12
+",
+            trace(&program, 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn analysis() {
+        let program = parse_input(&monad(&BLOCKS)).unwrap();
+        let blocks = analyze(&program).unwrap();
+        assert_eq!(
+            Block {
+                pops: true,
+                check: -3,
+                offset: 15
+            },
+            blocks[3]
+        );
+
+        let constraints = constraints(&blocks).unwrap();
+        assert_eq!(7, constraints.len());
+        assert!(constraints.contains(&Constraint {
+            first: 2,
+            second: 3,
+            difference: -1,
+        }));
+
+        assert!(analyze(&program[1..]).is_none());
+
+        // The digits of the first pair would have to differ by 9
+        let mut blocks = BLOCKS;
+        blocks[3].1 = 7;
+        let program = parse_input(&monad(&blocks)).unwrap();
+        assert!(crate::constraints(&analyze(&program).unwrap()).is_none());
+    }
+
+    #[test]
+    fn model_numbers() {
+        let program = parse_input(&monad(&BLOCKS)).unwrap();
+        let constraints = constraints(&analyze(&program).unwrap()).unwrap();
+
+        let largest = largest_model_number(&constraints);
+        let smallest = smallest_model_number(&constraints);
+        assert_eq!(65984919997939, largest);
+        assert_eq!(11211619541713, smallest);
+
+        assert!(is_valid(&program, largest));
+        assert!(is_valid(&program, smallest));
+        assert!(!is_valid(&program, largest - 1));
+        assert!(!is_valid(&program, smallest + 1));
+    }
+}
@@ -0,0 +1,5 @@
+use day24_alu::{ArithmeticLogicUnit, BUNDLED_INPUT};
+
+fn main() {
+    aoc_core::run::<ArithmeticLogicUnit>(BUNDLED_INPUT);
+}
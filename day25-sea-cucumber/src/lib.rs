@@ -0,0 +1,129 @@
+//! Day 25: Sea Cucumber.
+//!
+//! Simulates two herds of sea cucumbers moving across the sea floor until they
+//! all get stuck.
+
+#![warn(missing_docs)]
+
+use std::convert::Infallible;
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Position};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves day 25.
+pub struct SeaCucumber;
+
+impl Solution for SeaCucumber {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+
+    type Parsed<'a> = Map;
+    type Part1 = usize;
+    type Part2 = Infallible;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Map) -> Option<usize> {
+        Some(simulate_until_stable(&mut map.clone()))
+    }
+
+    fn part2(_: &Map) -> Option<Infallible> {
+        None
+    }
+}
+
+/// Moves the herds until no sea cucumber can move anymore.
+///
+/// ## Returns
+///
+/// The first step in which no sea cucumber moved.
+pub fn simulate_until_stable(map: &mut Map) -> usize {
+    let mut step = 1;
+    loop {
+        let any_moved_east = move_cucumbers(map, Cucumber::East);
+        let any_moved_south = move_cucumbers(map, Cucumber::South);
+
+        if !any_moved_east && !any_moved_south {
+            break step;
+        }
+
+        step += 1;
+    }
+}
+
+fn move_cucumbers(map: &mut Map, cucumber_type: Cucumber) -> bool {
+    let to_move: Vec<_> = map
+        .iter_with_positions()
+        .filter(|(_, cucumber)| cucumber.as_ref() == Some(&cucumber_type))
+        .filter_map(|(position, _)| {
+            let new_position = cucumber_type.new_position(map, position);
+            if map[new_position].is_none() {
+                Some((position, new_position))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let any_moved = !to_move.is_empty();
+
+    for (move_from, move_to) in to_move {
+        map[move_to] = map[move_from].take();
+    }
+
+    any_moved
+}
+
+/// Which herd a sea cucumber belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cucumber {
+    /// Moves to the right, wrapping around to the left edge.
+    East,
+    /// Moves down, wrapping around to the top edge.
+    South,
+}
+
+impl Cucumber {
+    fn new_position(&self, map: &Map, position: Position) -> Position {
+        match self {
+            Cucumber::East => map.wrapping_offset(position, (1, 0)),
+            Cucumber::South => map.wrapping_offset(position, (0, 1)),
+        }
+    }
+}
+
+/// The sea floor. Empty locations are `None`.
+pub type Map = Grid<Option<Cucumber>>;
+
+/// Parses the sea floor, where `>` faces east, `v` faces south, and `.` is
+/// empty. Every line has to be equally long.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Grid::parse_with(input, |c| match c {
+        '>' => Some(Some(Cucumber::East)),
+        'v' => Some(Some(Cucumber::South)),
+        '.' => Some(None),
+        _ => None,
+    })
+}
+
+#[test]
+fn test() {
+    let mut map = parse(
+        "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>",
+    )
+    .unwrap();
+
+    assert_eq!(58, simulate_until_stable(&mut map));
+}
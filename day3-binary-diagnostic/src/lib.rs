@@ -0,0 +1,243 @@
+//! Day 3: Binary Diagnostic.
+//!
+//! Decodes the submarine's diagnostic report, a list of equally long binary
+//! numbers, by looking at the most and least common bit in each position.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 3.
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Parsed<'a> = Vec<&'a [u8]>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Vec<&[u8]>) -> Option<u32> {
+        Some(calculate_power_consumption(lines))
+    }
+
+    fn part2(lines: &Vec<&[u8]>) -> Option<u32> {
+        Some(calculate_life_support_rating(lines))
+    }
+}
+
+/// Splits the report into its lines, as raw bytes (`b'0'` and `b'1'`). Every line
+/// has to be equally long.
+pub fn parse_input(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+    let mut lines = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if let Some(column) = line.find(|c| c != '0' && c != '1') {
+            return Err(ParseError::at(
+                index,
+                line,
+                &line[column..],
+                "Expected only 0s and 1s",
+            ));
+        }
+
+        let expected = lines
+            .first()
+            .map_or(line.len(), |first: &&[u8]| first.len());
+        if line.len() != expected {
+            return Err(ParseError::new(
+                index + 1,
+                line.len().min(expected) + 1,
+                format!("Line is {} bits long, expected {}", line.len(), expected),
+            ));
+        }
+
+        lines.push(line.as_bytes());
+    }
+
+    if lines.is_empty() {
+        return Err(ParseError::end_of_input(input, "The report is empty"));
+    }
+
+    Ok(lines)
+}
+
+/// Multiplies the gamma rate (the most common bit in each position) with the
+/// epsilon rate (the least common bit in each position).
+pub fn calculate_power_consumption(lines: &[&[u8]]) -> u32 {
+    let line_length = lines[0].len();
+
+    let mut gamma_rate = 0;
+    let mut epsilon_rate = 0;
+    for i in 0..line_length {
+        let mut zeroes = 0;
+        let mut ones = 0;
+
+        for line in lines.iter() {
+            let char = line[i];
+            match char {
+                b'0' => zeroes += 1,
+                b'1' => ones += 1,
+                _ => {}
+            }
+        }
+
+        let gamma_bit = if zeroes > ones { 0 } else { 1 };
+        let epsilon_bit = if zeroes < ones { 0 } else { 1 };
+
+        gamma_rate = gamma_rate << 1 | gamma_bit;
+        epsilon_rate = epsilon_rate << 1 | epsilon_bit;
+    }
+
+    gamma_rate * epsilon_rate
+}
+
+/// Which bit to keep the lines with while filtering down the report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitCriteria {
+    /// The most common bit, or 1 if both are equally common. Finds the oxygen
+    /// generator rating.
+    MostCommon,
+    /// The least common bit, or 0 if both are equally common. Finds the CO2
+    /// scrubber rating.
+    LeastCommon,
+}
+
+/// Filters the lines position by position, keeping only those with the bit the
+/// criteria picks among the lines that are left, until a single line remains.
+/// Positions where every line left has the same bit are skipped, so if the
+/// report has duplicates, several identical lines can remain at the end.
+///
+/// ## Returns
+///
+/// The remaining line as a number.
+pub fn find_rating(lines: &[&[u8]], criteria: BitCriteria) -> u32 {
+    let mut remaining = lines.to_vec();
+
+    for i in 0..lines[0].len() {
+        if remaining.len() == 1 {
+            break;
+        }
+
+        let ones = remaining.iter().filter(|line| line[i] == b'1').count();
+        let zeroes = remaining.len() - ones;
+        if ones == 0 || zeroes == 0 {
+            continue;
+        }
+
+        let keep = match criteria {
+            BitCriteria::MostCommon => {
+                if ones >= zeroes {
+                    b'1'
+                } else {
+                    b'0'
+                }
+            }
+            BitCriteria::LeastCommon => {
+                if ones >= zeroes {
+                    b'0'
+                } else {
+                    b'1'
+                }
+            }
+        };
+
+        remaining.retain(|line| line[i] == keep);
+    }
+
+    remaining[0]
+        .iter()
+        .fold(0, |rating, bit| rating << 1 | (bit - b'0') as u32)
+}
+
+/// Multiplies the oxygen generator rating with the CO2 scrubber rating.
+pub fn calculate_life_support_rating(lines: &[&[u8]]) -> u32 {
+    find_rating(lines, BitCriteria::MostCommon) * find_rating(lines, BitCriteria::LeastCommon)
+}
+
+#[test]
+fn test() {
+    assert_eq!(
+        198,
+        calculate_power_consumption(
+            &parse_input(
+                "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010"
+            )
+            .unwrap()
+        )
+    );
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+#[test]
+fn test_part2() {
+    let lines = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(23, find_rating(&lines, BitCriteria::MostCommon));
+    assert_eq!(10, find_rating(&lines, BitCriteria::LeastCommon));
+    assert_eq!(230, calculate_life_support_rating(&lines));
+}
+
+#[test]
+fn test_rating_ties() {
+    // Both bits are equally common in the first position
+    let lines = parse_input("10\n01").unwrap();
+
+    assert_eq!(2, find_rating(&lines, BitCriteria::MostCommon));
+    assert_eq!(1, find_rating(&lines, BitCriteria::LeastCommon));
+}
+
+#[test]
+fn test_rating_shared_bits() {
+    // Every line has a 1 in the first position, so there is nothing to filter
+    let lines = parse_input("10\n11").unwrap();
+    assert_eq!(3, find_rating(&lines, BitCriteria::MostCommon));
+    assert_eq!(2, find_rating(&lines, BitCriteria::LeastCommon));
+
+    let lines = parse_input("10\n10").unwrap();
+    assert_eq!(2, find_rating(&lines, BitCriteria::MostCommon));
+    assert_eq!(2, find_rating(&lines, BitCriteria::LeastCommon));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 2, column 3: Expected only 0s and 1s",
+        parse_input("00100\n11210").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 2, column 5: Line is 4 bits long, expected 5",
+        parse_input("00100\n1111").unwrap_err().to_string()
+    );
+}
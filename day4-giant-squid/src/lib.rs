@@ -0,0 +1,247 @@
+//! Day 4: Giant Squid.
+//!
+//! Plays bingo against a giant squid: finds the board that wins first, and the
+//! one that wins last.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 4.
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Parsed<'a> = (Vec<u8>, Vec<Board>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<u8>, Vec<Board>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((numbers, boards): &(Vec<u8>, Vec<Board>)) -> Option<usize> {
+        let (winning_number, winner) = pick_winner(numbers, boards.clone());
+
+        winner.map(|winner| winner.get_score(winning_number))
+    }
+
+    fn part2((numbers, boards): &(Vec<u8>, Vec<Board>)) -> Option<usize> {
+        let (winning_number, winner) = pick_last_winner(numbers, boards.clone());
+
+        winner.map(|winner| winner.get_score(winning_number))
+    }
+}
+
+/// A 5x5 bingo board, keeping track of which of its numbers have been picked.
+#[derive(Debug, Clone)]
+pub struct Board {
+    numbers: [u8; 25],
+    picked: [bool; 25],
+}
+
+impl Board {
+    /// Creates a board from its 25 numbers, row by row.
+    ///
+    /// ## Panics
+    ///
+    /// If there are not exactly 25 numbers.
+    pub fn new(numbers: &[u8]) -> Self {
+        Self {
+            numbers: numbers.try_into().expect("Incorrect board size"),
+            picked: [false; 25],
+        }
+    }
+
+    /// Marks `picked_num` on the board, and returns whether the board has now won.
+    pub fn wins_after(&mut self, picked_num: u8) -> bool {
+        for (index, num) in self.numbers.iter().enumerate() {
+            if *num == picked_num {
+                self.picked[index] = true;
+            }
+        }
+
+        self.wins()
+    }
+
+    /// Whether any full row or column has been picked.
+    pub fn wins(&self) -> bool {
+        [
+            0usize, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 0, 5, 10, 15, 20, 1, 6, 11, 16, 21, 2, 7, 12, 17, 22, 3, 8, 13, 18, 23, 4, 9,
+            14, 19, 24,
+        ]
+        .chunks(5)
+        .any(|window| window.iter().all(|index| self.picked[*index]))
+    }
+
+    /// The sum of all numbers that have not been picked, times the number that was
+    /// picked last.
+    pub fn get_score(&self, winning_number: u8) -> usize {
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, num)| {
+                if !self.picked[index] {
+                    Some(*num as usize)
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>()
+            * (winning_number as usize)
+    }
+}
+
+/// Parses the numbers that will be drawn, followed by the bingo boards.
+pub fn parse_input(input: &str) -> Result<(Vec<u8>, Vec<Board>), ParseError> {
+    let mut lines = input.lines().enumerate();
+    let numbers = match lines.next() {
+        Some((index, line)) => line
+            .split(',')
+            .map(|number| aoc_core::parse::number(index, line, number))
+            .collect::<Result<Vec<u8>, _>>()?,
+        None => return Err(ParseError::new(1, 1, "Expected the numbers to draw")),
+    };
+
+    let mut boards = Vec::new();
+    let mut buffer = Vec::with_capacity(25);
+    // The line the board in the buffer starts on, to point errors at.
+    let mut board_start = 0;
+
+    let mut finish_board = |buffer: &mut Vec<u8>, board_start: usize| {
+        if buffer.len() != 25 {
+            return Err(ParseError::new(
+                board_start + 1,
+                1,
+                format!("Board has {} numbers, expected 25", buffer.len()),
+            ));
+        }
+
+        boards.push(Board::new(buffer));
+        buffer.clear();
+
+        Ok(())
+    };
+
+    for (index, line) in lines {
+        if line.trim().is_empty() {
+            if !buffer.is_empty() {
+                finish_board(&mut buffer, board_start)?;
+            }
+
+            continue;
+        }
+
+        if buffer.is_empty() {
+            board_start = index;
+        }
+
+        for number in line.split_ascii_whitespace() {
+            buffer.push(aoc_core::parse::number(index, line, number)?);
+        }
+    }
+
+    if !buffer.is_empty() {
+        finish_board(&mut buffer, board_start)?;
+    }
+
+    Ok((numbers, boards))
+}
+
+/// Draws numbers until a board wins.
+///
+/// ## Returns
+///
+/// The number that made it win, and the winning board. If no board ever wins,
+/// `(0, None)`.
+pub fn pick_winner(numbers: &[u8], mut boards: Vec<Board>) -> (u8, Option<Board>) {
+    for num in numbers {
+        for board in boards.iter_mut() {
+            if board.wins_after(*num) {
+                return (*num, Some(board.clone()));
+            }
+        }
+    }
+
+    (0, None)
+}
+
+/// Draws numbers until every board has won.
+///
+/// ## Returns
+///
+/// The number that made the last board win, and that board. If not every board
+/// wins, `(0, None)`.
+pub fn pick_last_winner(numbers: &[u8], mut boards: Vec<Board>) -> (u8, Option<Board>) {
+    let mut winners = Vec::with_capacity(boards.len());
+
+    for num in numbers {
+        let new_boards = boards
+            .into_iter()
+            .filter_map(|mut board| {
+                if board.wins_after(*num) {
+                    winners.push(board);
+                    None
+                } else {
+                    Some(board)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if new_boards.is_empty() {
+            return (*num, winners.pop());
+        }
+
+        boards = new_boards;
+    }
+
+    (0, None)
+}
+
+#[test]
+fn test() {
+    let (numbers, boards) = parse_input(
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+    )
+    .unwrap();
+
+    let (winning_number, winner) = pick_winner(&numbers, boards);
+    assert!(winner.is_some());
+    assert_eq!(4512, winner.unwrap().get_score(winning_number));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 1, column 5: Expected a number, found `x`",
+        parse_input("7,4,x").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 3, column 1: Board has 5 numbers, expected 25",
+        parse_input("7,4\n\n1 2 3 4 5\n").unwrap_err().to_string()
+    );
+}
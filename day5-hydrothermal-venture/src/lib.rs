@@ -0,0 +1,289 @@
+//! Day 5: Hydrothermal Venture.
+//!
+//! Draws lines of hydrothermal vents onto a grid, and counts the points where
+//! at least two of them overlap.
+
+#![warn(missing_docs)]
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::Grid;
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 5.
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Parsed<'a> = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Option<usize> {
+        Some(count_overlapping_points(
+            &lines
+                .iter()
+                .filter(|line| line.is_horizontal() || line.is_vertical())
+                .cloned()
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Option<usize> {
+        Some(count_overlapping_points(lines))
+    }
+}
+
+/// A point on the ocean floor, as `(x, y)`. Also used as the direction and
+/// distance between two points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point(pub isize, pub isize);
+
+impl Point {
+    /// The number of steps to get here from `(0, 0)`, moving horizontally,
+    /// vertically, or diagonally.
+    fn len(&self) -> isize {
+        self.0.abs().max(self.1.abs())
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Div<isize> for Point {
+    type Output = Point;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        Point(self.0 / rhs, self.1 / rhs)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+/// A line of vents between two points (inclusive).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line(pub Point, pub Point);
+
+impl Line {
+    /// Whether both points lie in the same column.
+    ///
+    /// Note that this is what the original puzzle solution called horizontal. Only
+    /// the combination with [`Line::is_vertical`] matters.
+    pub fn is_horizontal(&self) -> bool {
+        self.0 .0 == self.1 .0
+    }
+
+    /// Whether both points lie on the same row.
+    pub fn is_vertical(&self) -> bool {
+        self.0 .1 == self.1 .1
+    }
+
+    /// All points covered by this line, from start to end. Only horizontal,
+    /// vertical, and 45 degree diagonal lines are supported.
+    pub fn points(&self) -> Vec<Point> {
+        let delta = self.1 - self.0;
+        let distance = delta.len();
+        if distance == 0 {
+            return vec![self.0];
+        }
+
+        let delta_step = delta / distance;
+
+        (0..=distance)
+            .map(|step| self.0 + delta_step * step)
+            .collect()
+    }
+}
+
+/// Parses one line of vents per line, such as `0,9 -> 5,9`. Lines have to be
+/// horizontal, vertical, or diagonal at 45 degrees.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (from, to) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::at(index, line, line, "Expected `x,y -> x,y`"))?;
+
+            let vents = Line(
+                parse_point(index, line, from)?,
+                parse_point(index, line, to)?,
+            );
+            let delta = vents.1 - vents.0;
+            if delta.0 != 0 && delta.1 != 0 && delta.0.abs() != delta.1.abs() {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Lines have to be horizontal, vertical, or diagonal at 45 degrees",
+                ));
+            }
+
+            Ok(vents)
+        })
+        .collect()
+}
+
+/// Parses the `point` part of a line, such as `0,9`. Points can't be negative,
+/// since they have to fit on the grid.
+fn parse_point(index: usize, line: &str, point: &str) -> Result<Point, ParseError> {
+    let (x, y) = point
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(index, line, point, "Expected a point, such as `0,9`"))?;
+    let coordinate = |part| {
+        aoc_core::parse::number::<usize>(index, line, part).map(|coordinate| coordinate as isize)
+    };
+
+    Ok(Point(coordinate(x)?, coordinate(y)?))
+}
+
+/// Counts for every point on the grid how many lines cover it. The grid is
+/// large enough to contain every line.
+pub fn fill_grid(lines: &[Line]) -> Grid<usize> {
+    let width = lines
+        .iter()
+        .flat_map(|line| [line.0 .0, line.1 .0])
+        .max()
+        .unwrap_or_default() as usize
+        + 1;
+    let height = lines
+        .iter()
+        .flat_map(|line| [line.0 .1, line.1 .1])
+        .max()
+        .unwrap_or_default() as usize
+        + 1;
+
+    let mut grid = Grid::filled(width, height, 0);
+    for line in lines {
+        for Point(x, y) in line.points() {
+            grid[(x as usize, y as usize)] += 1;
+        }
+    }
+
+    grid
+}
+
+/// Counts the points covered by at least two lines.
+pub fn count_overlapping_points(lines: &[Line]) -> usize {
+    fill_grid(lines).iter().filter(|value| **value >= 2).count()
+}
+
+#[test]
+fn test_part1() {
+    let lines = parse(
+        "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+    )
+    .unwrap();
+
+    let grid = fill_grid(
+        &lines
+            .into_iter()
+            .filter(|line| line.is_horizontal() || line.is_vertical())
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(5, grid.iter().filter(|value| **value >= 2).count());
+}
+
+#[test]
+fn test_covered_points() {
+    // Horizontal
+    assert_eq!(
+        vec![Point(15, 2), Point(16, 2), Point(17, 2), Point(18, 2)],
+        Line(Point(15, 2), Point(18, 2)).points()
+    );
+
+    // Vertical
+    assert_eq!(
+        vec![Point(1, 2), Point(1, 3), Point(1, 4)],
+        Line(Point(1, 2), Point(1, 4)).points()
+    );
+
+    // Vertical going up
+    assert_eq!(
+        vec![Point(1, 4), Point(1, 3), Point(1, 2)],
+        Line(Point(1, 4), Point(1, 2)).points()
+    );
+
+    // Diagonal
+    assert_eq!(
+        vec![Point(1, 1), Point(2, 2), Point(3, 3), Point(4, 4)],
+        Line(Point(1, 1), Point(4, 4)).points()
+    );
+
+    // One point
+    assert_eq!(vec![Point(1, 1)], Line(Point(1, 1), Point(1, 1)).points());
+}
+
+#[test]
+fn test_part2() {
+    let lines = parse(
+        "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+    )
+    .unwrap();
+
+    let grid = fill_grid(&lines);
+    assert_eq!(12, grid.iter().filter(|value| **value >= 2).count());
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 2, column 8: Expected a number, found `-1`",
+        parse("0,9 -> 5,9\n8,0 -> -1,8").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 1, column 1: Expected `x,y -> x,y`",
+        parse("0,9 => 5,9").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 1, column 1: Lines have to be horizontal, vertical, or diagonal at 45 degrees",
+        parse("0,0 -> 1,3").unwrap_err().to_string()
+    );
+}
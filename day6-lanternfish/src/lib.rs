@@ -0,0 +1,119 @@
+//! Day 6: Lanternfish.
+//!
+//! Simulates an exponentially growing school of lanternfish, where every fish
+//! spawns a new one every 7 days.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 6.
+pub struct Lanternfish;
+
+impl Solution for Lanternfish {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Parsed<'a> = Vec<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(fish: &Vec<u8>) -> Option<usize> {
+        Some(simulate_naive(fish.clone(), 80).len())
+    }
+
+    fn part2(fish: &Vec<u8>) -> Option<usize> {
+        Some(simulate_optimised(fish, 256))
+    }
+}
+
+/// Parses the comma separated internal timers of each fish.
+pub fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let line = input.trim();
+
+    line.split(',')
+        .map(|timer| match aoc_core::parse::number(0, line, timer)? {
+            timer @ 0..=8 => Ok(timer),
+            _ => Err(ParseError::at(0, line, timer, "Timers go from 0 to 8")),
+        })
+        .collect()
+}
+
+/// Simulates every fish individually, and returns the resulting school. Only
+/// feasible for a small number of days.
+pub fn simulate_naive(mut fish: Vec<u8>, days: usize) -> Vec<u8> {
+    for _ in 0..days {
+        let mut new_fish = 0;
+
+        for f in fish.iter_mut() {
+            if *f == 0 {
+                *f = 6;
+                new_fish += 1;
+            } else {
+                *f -= 1;
+            }
+        }
+
+        fish.extend(std::iter::repeat_n(8, new_fish));
+    }
+
+    fish
+}
+
+/// Simulates the school by only counting how many fish have each timer value,
+/// and returns the number of fish after `days`.
+pub fn simulate_optimised(fish: &[u8], days: usize) -> usize {
+    let mut counts = [0; 9];
+    for f in fish {
+        counts[*f as usize] += 1;
+    }
+
+    for _ in 0..days {
+        counts = [
+            // Shift all the counts down by 1
+            counts[1],
+            counts[2],
+            counts[3],
+            counts[4],
+            counts[5],
+            counts[6],
+            // 7 goes down to 6, and everything that was at 0 goes back to 6
+            counts[0] + counts[7],
+            counts[8],
+            // Everything that was at 0 spawns a new 8
+            counts[0],
+        ];
+    }
+
+    counts.iter().sum()
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input("3,4,3,1,2").unwrap();
+
+    assert_eq!(5934, simulate_naive(input.clone(), 80).len());
+    assert_eq!(5934, simulate_optimised(&input, 80));
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input("3,4,3,1,2").unwrap();
+
+    assert_eq!(26984457539, simulate_optimised(&input, 256));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 1, column 3: Timers go from 0 to 8",
+        parse_input("3,9,3").unwrap_err().to_string()
+    );
+}
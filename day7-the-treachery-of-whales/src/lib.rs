@@ -0,0 +1,117 @@
+//! Day 7: The Treachery of Whales.
+//!
+//! Finds the cheapest position for a swarm of crab submarines to align on.
+
+#![warn(missing_docs)]
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 7.
+pub struct TreacheryOfWhales;
+
+impl Solution for TreacheryOfWhales {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Parsed<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(crab_positions: &Vec<i32>) -> Option<i32> {
+        Some(lowest_alignment_cost_mean(crab_positions.clone()))
+    }
+
+    fn part2(crab_positions: &Vec<i32>) -> Option<i32> {
+        Some(lowest_alignment_cost_range(crab_positions.clone()))
+    }
+}
+
+/// Parses the comma separated horizontal positions of the crabs, none of which
+/// can be negative.
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let line = input.trim();
+
+    line.split(',')
+        .map(|position| {
+            let number = aoc_core::parse::number(0, line, position)?;
+            if number < 0 {
+                return Err(ParseError::at(
+                    0,
+                    line,
+                    position,
+                    "Positions can't be negative",
+                ));
+            }
+
+            Ok(number)
+        })
+        .collect()
+}
+
+/// The lowest fuel cost when each step costs 1 fuel. The best position is the
+/// median of all positions.
+pub fn lowest_alignment_cost_mean(mut crab_positions: Vec<i32>) -> i32 {
+    crab_positions.sort_unstable();
+
+    let mean = crab_positions[crab_positions.len() / 2];
+
+    crab_positions.iter().map(|pos| (pos - mean).abs()).sum()
+}
+
+/// The lowest fuel cost when each step costs 1 more fuel than the previous one,
+/// found by trying every position.
+///
+/// ## Panics
+///
+/// If there are no crabs.
+pub fn lowest_alignment_cost_range(crab_positions: Vec<i32>) -> i32 {
+    let max = crab_positions
+        .iter()
+        .max()
+        .expect("The crab positions vec should not be empty");
+
+    (0..=*max)
+        .map(|target| {
+            crab_positions
+                .iter()
+                .flat_map(|pos| 1..=((pos - target).abs()))
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        37,
+        lowest_alignment_cost_mean(parse_input("16,1,2,0,4,2,7,1,2,14").unwrap())
+    )
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        168,
+        lowest_alignment_cost_range(parse_input("16,1,2,0,4,2,7,1,2,14").unwrap())
+    )
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 1, column 4: Expected a number",
+        parse_input("16,,2").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 1, column 4: Positions can't be negative",
+        parse_input("16,-2").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,323 @@
+//! Day 8: Seven Segment Search.
+//!
+//! Works out which digits are shown on the submarine's scrambled seven segment
+//! displays.
+
+#![warn(missing_docs)]
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use aoc_core::{ParseError, Solution};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 8.
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Parsed<'a> = Vec<Entry<'a>>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(entries: &Vec<Entry<'_>>) -> Option<usize> {
+        Some(count_occurences(entries, &[1, 4, 7, 8]))
+    }
+
+    fn part2(entries: &Vec<Entry<'_>>) -> Option<u32> {
+        sum_output_values(entries)
+    }
+}
+
+/// A single note: the ten unique signal patterns seen on one display, and the
+/// four digit output value it shows, both as scrambled segments.
+#[derive(Debug)]
+pub struct Entry<'a> {
+    /// The ten unique patterns, one for each digit.
+    pub signal_patterns: Vec<&'a str>,
+    /// The four digits of the output value.
+    pub output_values: Vec<&'a str>,
+}
+
+/// Parses one entry per line, with the ten patterns and the four output values
+/// separated by ` | `.
+pub fn parse_input(input: &str) -> Result<Vec<Entry<'_>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (signal_patterns, output_values) = line.split_once(" | ").ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    line,
+                    "Expected patterns and values separated by ` | `",
+                )
+            })?;
+
+            Ok(Entry {
+                signal_patterns: parse_segments(index, line, signal_patterns, 10)?,
+                output_values: parse_segments(index, line, output_values, 4)?,
+            })
+        })
+        .collect()
+}
+
+/// Splits `part` of a line into `count` patterns of the segments `a` to `g`.
+fn parse_segments<'a>(
+    index: usize,
+    line: &str,
+    part: &'a str,
+    count: usize,
+) -> Result<Vec<&'a str>, ParseError> {
+    if let Some(column) = part.find(|c: char| !matches!(c, 'a'..='g' | ' ')) {
+        return Err(ParseError::at(
+            index,
+            line,
+            &part[column..],
+            "Segments go from a to g",
+        ));
+    }
+
+    let patterns: Vec<_> = part.split_ascii_whitespace().collect();
+    if patterns.len() != count {
+        return Err(ParseError::at(
+            index,
+            line,
+            part,
+            format!("Expected {} patterns, found {}", count, patterns.len()),
+        ));
+    }
+
+    Ok(patterns)
+}
+
+/// Counts how often the digits in `search_for` appear in the output values.
+/// Only digits with a unique number of segments (1, 4, 7, and 8) can be told
+/// apart this way.
+///
+/// ## Panics
+///
+/// If `search_for` contains a number that is not a single digit.
+pub fn count_occurences(input: &[Entry], search_for: &[u8]) -> usize {
+    let search_for: HashSet<_> = search_for
+        .iter()
+        .map(|num| match num {
+            0 => 6,
+            1 => 2,
+            2 => 5,
+            3 => 5,
+            4 => 4,
+            5 => 5,
+            6 => 6,
+            7 => 3,
+            8 => 7,
+            9 => 6,
+            _ => panic!("Unexpected search term"),
+        })
+        .collect();
+
+    input
+        .iter()
+        .map(|entry| {
+            entry
+                .output_values
+                .iter()
+                .filter(|value| search_for.contains(&value.len()))
+                .count()
+        })
+        .sum()
+}
+
+/// The segments each digit lights up on a display that is wired correctly.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Which segment each wire of a display is connected to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wiring {
+    /// The segment wire `a` lights up, then the one wire `b` lights up, and so
+    /// on.
+    pub segments: [char; 7],
+}
+
+impl Wiring {
+    /// Works out the digit a scrambled pattern shows, if it shows one.
+    pub fn decode_digit(&self, pattern: &str) -> Option<u8> {
+        let mut segments: Vec<_> = pattern
+            .bytes()
+            .map(|wire| self.segments[(wire - b'a') as usize])
+            .collect();
+        segments.sort_unstable();
+        let segments: String = segments.into_iter().collect();
+
+        DIGITS
+            .iter()
+            .position(|&digit| digit == segments)
+            .map(|digit| digit as u8)
+    }
+
+    /// Works out the number the scrambled patterns show, one digit each.
+    pub fn decode(&self, patterns: &[&str]) -> Option<u32> {
+        patterns.iter().try_fold(0, |number, pattern| {
+            Some(number * 10 + self.decode_digit(pattern)? as u32)
+        })
+    }
+}
+
+impl Display for Wiring {
+    /// Lists each wire with its segment, such as `a-c b-f ...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (wire, segment) in ('a'..='g').zip(self.segments) {
+            if wire != 'a' {
+                write!(f, " ")?;
+            }
+            write!(f, "{}-{}", wire, segment)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Works out how the display of an entry is wired, from its ten signal
+/// patterns.
+///
+/// Across all ten digits, segments `b`, `e` and `f` are each lit a different
+/// number of times (6, 4 and 9). Of the two segments lit 8 times, only `c` is
+/// part of 1, and of the two lit 7 times, only `d` is part of 4.
+///
+/// ## Returns
+///
+/// The wiring, or `None` if the patterns aren't the ten digits of any wiring.
+pub fn deduce_wiring(entry: &Entry) -> Option<Wiring> {
+    let pattern_with_length = |length| {
+        entry
+            .signal_patterns
+            .iter()
+            .find(|pattern| pattern.len() == length)
+    };
+    let one = pattern_with_length(2)?;
+    let four = pattern_with_length(4)?;
+
+    let mut segments = ['?'; 7];
+    for (wire, segment) in ('a'..='g').zip(&mut segments) {
+        let count = entry
+            .signal_patterns
+            .iter()
+            .filter(|pattern| pattern.contains(wire))
+            .count();
+
+        *segment = match count {
+            4 => 'e',
+            6 => 'b',
+            7 if four.contains(wire) => 'd',
+            7 => 'g',
+            8 if one.contains(wire) => 'c',
+            8 => 'a',
+            9 => 'f',
+            _ => return None,
+        };
+    }
+
+    let wiring = Wiring { segments };
+    let digits: HashSet<_> = entry
+        .signal_patterns
+        .iter()
+        .map(|pattern| wiring.decode_digit(pattern))
+        .collect::<Option<_>>()?;
+
+    (digits.len() == 10).then_some(wiring)
+}
+
+/// Works out the output value of an entry.
+pub fn decode_output_value(entry: &Entry) -> Option<u32> {
+    deduce_wiring(entry)?.decode(&entry.output_values)
+}
+
+/// Adds up the output values of every entry.
+///
+/// ## Returns
+///
+/// The sum, or `None` if the wiring of any entry can't be worked out.
+pub fn sum_output_values(entries: &[Entry]) -> Option<u32> {
+    entries.iter().map(decode_output_value).sum()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str =
+    "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+#[test]
+fn test() {
+    assert_eq!(
+        26,
+        count_occurences(&parse_input(TEST_INPUT).unwrap(), &[1, 4, 7, 8])
+    )
+}
+
+#[test]
+fn test_part2() {
+    let entries = parse_input(TEST_INPUT).unwrap();
+
+    assert_eq!(
+        vec![8394, 9781, 1197, 9361, 4873, 8418, 4548, 1625, 8717, 4315],
+        entries
+            .iter()
+            .map(|entry| decode_output_value(entry).unwrap())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some(61229), sum_output_values(&entries));
+}
+
+#[test]
+fn test_deduce_wiring() {
+    let entries = parse_input(
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+    )
+    .unwrap();
+    let wiring = deduce_wiring(&entries[0]).unwrap();
+
+    assert_eq!("a-c b-f c-g d-a e-b f-d g-e", wiring.to_string());
+    assert_eq!(Some(5353), wiring.decode(&entries[0].output_values));
+
+    // Two patterns for 1, and none for 7
+    let entries = parse_input(
+        "acedgfb cdfbe gcdfa fbcad ab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+    )
+    .unwrap();
+    assert_eq!(None, deduce_wiring(&entries[0]));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        "line 1, column 1: Expected patterns and values separated by ` | `",
+        parse_input("be cfbegad").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 2, column 62: Expected 4 patterns, found 3",
+        parse_input(&TEST_INPUT.replace("| fcgedb cgb", "| cgb"))
+            .unwrap_err()
+            .to_string()
+    );
+}
@@ -0,0 +1,128 @@
+//! Basins: the locations that flow down to each low point, bounded by height
+//! 9.
+
+use aoc_grid::{Grid, Position};
+
+use crate::find_low_point_positions;
+
+/// The height that is never part of a basin.
+const RIDGE: u8 = 9;
+
+/// All locations that flow down to a single low point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Basin {
+    /// The lowest location in the basin.
+    pub low_point: Position,
+    /// Every location in the basin, including the low point, in the order the
+    /// flood fill reached them.
+    pub locations: Vec<Position>,
+}
+
+impl Basin {
+    /// The number of locations in the basin.
+    pub fn size(&self) -> usize {
+        self.locations.len()
+    }
+}
+
+/// Finds every location connected to `start` without crossing a 9.
+pub fn flood_fill(grid: &Grid<u8>, start: Position) -> Vec<Position> {
+    if grid[start] == RIDGE {
+        return vec![];
+    }
+
+    let mut visited = Grid::filled(grid.width(), grid.height(), false);
+    visited[start] = true;
+    let mut locations = vec![start];
+    let mut next = 0;
+
+    while let Some(&location) = locations.get(next) {
+        next += 1;
+
+        for neighbour in grid.neighbours4(location) {
+            if grid[neighbour] != RIDGE && !visited[neighbour] {
+                visited[neighbour] = true;
+                locations.push(neighbour);
+            }
+        }
+    }
+
+    locations
+}
+
+/// Finds the basin around every low point, in the order of the low points.
+pub fn find_basins(grid: &Grid<u8>) -> Vec<Basin> {
+    find_low_point_positions(grid)
+        .into_iter()
+        .map(|low_point| Basin {
+            low_point,
+            locations: flood_fill(grid, low_point),
+        })
+        .collect()
+}
+
+/// Labels every location with the index of its basin in [`find_basins`], or
+/// `None` for locations that aren't part of a basin.
+pub fn label_basins(grid: &Grid<u8>) -> Grid<Option<usize>> {
+    let mut labels = Grid::filled(grid.width(), grid.height(), None);
+
+    for (id, basin) in find_basins(grid).iter().enumerate() {
+        for &location in &basin.locations {
+            labels[location] = Some(id);
+        }
+    }
+
+    labels
+}
+
+/// Multiplies the sizes of the `count` largest basins.
+pub fn largest_basins_product(basins: &[Basin], count: usize) -> usize {
+    let mut sizes: Vec<_> = basins.iter().map(Basin::size).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    sizes.iter().take(count).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        basin::{find_basins, flood_fill, label_basins, largest_basins_product},
+        parse_input, TEST_INPUT,
+    };
+
+    #[test]
+    fn basins() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+        let basins = find_basins(&grid);
+
+        assert_eq!(
+            vec![(1, 0), (9, 0), (2, 2), (6, 4)],
+            basins
+                .iter()
+                .map(|basin| basin.low_point)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 9, 14, 9],
+            basins.iter().map(|basin| basin.size()).collect::<Vec<_>>()
+        );
+        assert_eq!(1134, largest_basins_product(&basins, 3));
+        assert!(flood_fill(&grid, (2, 0)).is_empty());
+    }
+
+    #[test]
+    fn labels() {
+        let labels = label_basins(&parse_input(TEST_INPUT).unwrap());
+
+        assert_eq!(
+            "00...11111
+0.222.1.11
+.22222.3.1
+22222.333.
+.2...33333",
+            labels
+                .map(|label| label.map_or('.', |id| (b'0' + id as u8) as char))
+                .to_string()
+        );
+    }
+}
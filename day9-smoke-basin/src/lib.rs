@@ -0,0 +1,90 @@
+//! Day 9: Smoke Basin.
+//!
+//! Finds the low points in a height map of the caves, and the basins that
+//! flow down to them.
+
+#![warn(missing_docs)]
+
+pub mod basin;
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Position};
+
+/// Path to the puzzle input that ships with this crate.
+pub const BUNDLED_INPUT: &str = aoc_core::bundled_input!();
+
+/// Solves both parts of day 9.
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Option<u32> {
+        Some(risk_level_sum(&find_low_points(grid)))
+    }
+
+    fn part2(grid: &Grid<u8>) -> Option<usize> {
+        Some(basin::largest_basins_product(&basin::find_basins(grid), 3))
+    }
+}
+
+/// Parses the height map, one digit per location. Every line has to be equally
+/// long.
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input)
+}
+
+/// Finds the positions of all locations that are lower than each of their
+/// (orthogonal) neighbours, row by row.
+pub fn find_low_point_positions(grid: &Grid<u8>) -> Vec<Position> {
+    grid.iter_with_positions()
+        .filter_map(|(position, digit)| {
+            if grid
+                .neighbours4(position)
+                .all(|neighbour| *digit < grid[neighbour])
+            {
+                Some(position)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Finds the heights of all locations that are lower than each of their
+/// (orthogonal) neighbours.
+pub fn find_low_points(grid: &Grid<u8>) -> Vec<u8> {
+    find_low_point_positions(grid)
+        .into_iter()
+        .map(|position| grid[position])
+        .collect()
+}
+
+/// Sums the risk levels, i.e. the heights plus one, of the low points.
+pub fn risk_level_sum(low_points: &[u8]) -> u32 {
+    low_points.iter().map(|point| *point as u32 + 1).sum()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+#[test]
+fn test() {
+    assert_eq!(
+        15,
+        risk_level_sum(&find_low_points(&parse_input(TEST_INPUT).unwrap()))
+    );
+}